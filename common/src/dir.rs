@@ -6,11 +6,36 @@ pub enum Dir {
     South,
     East,
     West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
 }
 
 impl Dir {
+    /// The four cardinal directions.
+    pub const CARDINAL: [Dir; 4] = [Self::North, Self::South, Self::East, Self::West];
+
+    /// The four diagonal directions.
+    pub const DIAGONAL: [Dir; 4] = [
+        Self::NorthEast,
+        Self::NorthWest,
+        Self::SouthEast,
+        Self::SouthWest,
+    ];
+
+    /// The four cardinal directions.
     pub fn all() -> Vec<Self> {
-        vec![Self::North, Self::South, Self::East, Self::West]
+        Self::CARDINAL.to_vec()
+    }
+
+    /// The four cardinal directions, then the four diagonal ones.
+    pub fn all8() -> Vec<Self> {
+        [Self::CARDINAL, Self::DIAGONAL].concat()
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        Self::DIAGONAL.contains(self)
     }
 
     pub fn step(&self) -> Point {
@@ -19,7 +44,62 @@ impl Dir {
             Dir::South => (1, 0),
             Dir::East => (0, 1),
             Dir::West => (0, -1),
+            Dir::NorthEast => (-1, 1),
+            Dir::NorthWest => (-1, -1),
+            Dir::SouthEast => (1, 1),
+            Dir::SouthWest => (1, -1),
         }
         .into()
     }
+
+    /// The direction a quarter turn clockwise from this one.
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Dir::North => Self::East,
+            Dir::South => Self::West,
+            Dir::East => Self::South,
+            Dir::West => Self::North,
+            Dir::NorthEast => Self::SouthEast,
+            Dir::NorthWest => Self::NorthEast,
+            Dir::SouthEast => Self::SouthWest,
+            Dir::SouthWest => Self::NorthWest,
+        }
+    }
+
+    /// The direction a quarter turn anticlockwise from this one.
+    pub fn turn_left(&self) -> Self {
+        self.reverse().turn_right()
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_turn() {
+        for dir in Dir::all8() {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.reverse().step(), Point::new(0, 0) - dir.step());
+            assert_eq!(dir.turn_right().is_diagonal(), dir.is_diagonal());
+        }
+
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+        assert_eq!(Dir::NorthEast.turn_left(), Dir::NorthWest);
+    }
+
+    #[test]
+    fn should_step_in_every_direction() {
+        let steps = Dir::all8().iter().map(Dir::step).collect::<Vec<_>>();
+
+        assert_eq!(steps.len(), 8);
+        assert!(steps
+            .iter()
+            .all(|step| step.row.abs() <= 1 && step.col.abs() <= 1 && *step != Point::new(0, 0)));
+        assert!(steps[4..].iter().all(|step| step.row != 0 && step.col != 0));
+    }
 }
@@ -1,10 +1,15 @@
-use crate::point::Point;
+use crate::{dir::Dir, point::Point, puzzle::PuzzleError};
+use std::ops::{Index, IndexMut};
 
 mod a_star;
 
-#[derive(Clone, Debug)]
-/// Represents a 2D grid of tiles
-pub struct Grid<T>(pub Vec<Vec<T>>);
+/// Represents a 2D grid of tiles, stored row by row in a single buffer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    width: usize,
+    height: usize,
+}
 
 /// Defines a wrapped `Grid` with the given name and tile type,
 /// and implements some helper traits for the new type.
@@ -30,17 +35,95 @@ macro_rules! grid_def {
     };
 }
 
-// TODO: more tests
 impl<T> Grid<T> {
-    pub fn parse_with<F>(input: &str, f: F) -> Grid<T>
+    /// Makes a grid out of rows of tiles, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, PuzzleError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        let mut tiles = Vec::with_capacity(width * height);
+        for (idx, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(PuzzleError::new(format!(
+                    "expected every row of the grid to be {width} tiles long, not {}",
+                    row.len()
+                ))
+                .at_line(idx + 1));
+            }
+            tiles.extend(row);
+        }
+
+        Ok(Self {
+            tiles,
+            width,
+            height,
+        })
+    }
+
+    /// Makes a grid of the given size, with each tile worked out from its position.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Grid<T> {
+        let tiles = (0..height as i64)
+            .flat_map(|row| (0..width as i64).map(move |col| Point::new(row, col)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            tiles,
+            width,
+            height,
+        }
+    }
+
+    /// Parses each line of `input` as a row of the grid, turning each character
+    /// into a tile with `f`. Fails if the lines aren't all the same length.
+    pub fn try_parse_with<F>(input: &str, f: F) -> Result<Grid<T>, PuzzleError>
     where
         F: FnMut(char) -> T + Copy,
     {
-        let data = input
+        let rows = input
+            .trim_end_matches(['\n', '\r'])
             .lines()
             .map(|line| line.chars().map(f).collect())
             .collect();
-        Self(data)
+        Self::from_rows(rows)
+    }
+
+    /// Parses each line of `input` as a row of the grid, turning each character
+    /// into a tile with `f`, which can fail. Errors from `f` are given the line and
+    /// column of the character, and the lines must all be the same length.
+    pub fn try_parse_tiles<F>(input: &str, mut f: F) -> Result<Grid<T>, PuzzleError>
+    where
+        F: FnMut(char) -> Result<T, PuzzleError>,
+    {
+        let rows = input
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| f(c).map_err(|err| err.at(row + 1, col + 1)))
+                    .collect()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_rows(rows)
+    }
+
+    /// Parses a grid of tiles that can each be made from a character, failing on
+    /// any character that isn't a tile.
+    pub fn try_parse(input: &str) -> Result<Grid<T>, PuzzleError>
+    where
+        T: TryFrom<char, Error = PuzzleError>,
+    {
+        Self::try_parse_tiles(input, T::try_from)
+    }
+
+    /// Like `try_parse_with`, but panics if the lines aren't all the same length.
+    pub fn parse_with<F>(input: &str, f: F) -> Grid<T>
+    where
+        F: FnMut(char) -> T + Copy,
+    {
+        Self::try_parse_with(input, f).unwrap_or_else(|err| panic!("{err}"))
     }
 
     pub fn parse(input: &str) -> Grid<T>
@@ -50,52 +133,166 @@ impl<T> Grid<T> {
         Self::parse_with(input, T::from)
     }
 
+    /// Where the tile at `pt` is in the buffer, if it's in the grid.
+    fn index_of(&self, pt: Point) -> Option<usize> {
+        self.contains(pt)
+            .then(|| pt.row as usize * self.width + pt.col as usize)
+    }
+
+    /// Where the tile at `idx` in the buffer is in the grid.
+    fn point_of(&self, idx: usize) -> Point {
+        Point::new((idx / self.width) as i64, (idx % self.width) as i64)
+    }
+
     pub fn at(&self, pt: Point) -> Option<&T> {
-        if pt.row < 0 || pt.col < 0 {
-            None
-        } else {
-            self.0
-                .get(pt.row as usize)
-                .and_then(|row| row.get(pt.col as usize))
-        }
+        self.index_of(pt).map(|idx| &self.tiles[idx])
+    }
+
+    pub fn at_mut(&mut self, pt: Point) -> Option<&mut T> {
+        self.index_of(pt).map(|idx| &mut self.tiles[idx])
     }
 
     pub fn width(&self) -> usize {
-        self.0[0].len()
+        self.width
     }
 
     pub fn height(&self) -> usize {
-        self.0.len()
+        self.height
+    }
+
+    /// One row of the grid, if it's in the grid.
+    pub fn row(&self, idx: usize) -> Option<&[T]> {
+        (idx < self.height).then(|| &self.tiles[idx * self.width..(idx + 1) * self.width])
+    }
+
+    pub fn row_slices(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|idx| &self.tiles[idx * self.width..(idx + 1) * self.width])
     }
 
     pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = T> + '_> + '_
     where
         T: Clone,
     {
-        self.0.iter().map(|row| row.iter().cloned())
+        self.row_slices().map(|row| row.iter().cloned())
     }
 
     pub fn contains(&self, Point { row, col }: Point) -> bool {
-        row >= 0 && row < self.height() as i64 && col >= 0 && col < self.width() as i64
+        row >= 0 && row < self.height as i64 && col >= 0 && col < self.width as i64
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    /// Every tile in the grid along with its position, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.iter_points().zip(&self.tiles)
+    }
+
+    /// Every tile in the grid (mutably) along with its position, row by row.
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> + '_ {
+        self.iter_points().zip(&mut self.tiles)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.tiles.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.tiles.iter_mut()
+    }
+
+    /// The position of every tile that matches `pred`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        pred: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.enumerate()
+            .filter(move |(_, tile)| pred(tile))
+            .map(|(pt, _)| pt)
+    }
+
+    /// The position of every tile that's equal to `value`, row by row.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.find_all(move |tile| tile == value)
+    }
+
+    /// Every tile that matches `pred` (mutably), along with its position.
+    pub fn find_all_mut<'a>(
+        &'a mut self,
+        pred: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Point, &'a mut T)> + 'a {
+        self.enumerate_mut().filter(move |(_, tile)| pred(tile))
     }
 
     pub fn find_pt(&self, pred: impl Fn(T) -> bool) -> Option<Point>
     where
         T: Clone,
     {
-        self.rows().enumerate().find_map(|(row_idx, row)| {
-            row.enumerate().find_map(|(col_idx, tile)| {
-                if pred(tile) {
-                    Some((row_idx as i64, col_idx as i64).into())
-                } else {
-                    None
-                }
-            })
+        let idx = self.tiles.iter().position(|tile| pred(tile.clone()))?;
+        Some(self.point_of(idx))
+    }
+
+    /// The tiles next to `pt` (north, south, east and west) that are in the grid.
+    pub fn neighbors4(&self, pt: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbors_with(pt, Dir::CARDINAL.map(|dir| dir.step()))
+    }
+
+    /// The tiles next to `pt`, including diagonally, that are in the grid.
+    pub fn neighbors8(&self, pt: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let dirs = Dir::CARDINAL.into_iter().chain(Dir::DIAGONAL);
+        self.neighbors_with(pt, dirs.map(|dir| dir.step()))
+    }
+
+    /// The tiles at each of `offsets` from `pt` that are in the grid.
+    pub fn neighbors_with<'a, I>(
+        &'a self,
+        pt: Point,
+        offsets: I,
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a
+    where
+        I: IntoIterator<Item = Point>,
+        I::IntoIter: 'a,
+    {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbor = pt + offset;
+            self.at(neighbor).map(|tile| (neighbor, tile))
         })
     }
 
     pub fn put(&mut self, that: T, here: Point) {
-        self.0[here.row as usize][here.col as usize] = that;
+        self[here] = that;
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pt: Point) -> &T {
+        match self.index_of(pt) {
+            Some(idx) => &self.tiles[idx],
+            None => panic!(
+                "{pt:?} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pt: Point) -> &mut T {
+        match self.index_of(pt) {
+            Some(idx) => &mut self.tiles[idx],
+            None => panic!(
+                "{pt:?} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
     }
 }
 
@@ -121,6 +318,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::puzzle::Context;
 
     #[test]
     fn should_parse_number_grid() {
@@ -132,4 +330,100 @@ mod tests {
         assert_eq!(grid.height(), 3);
         assert_eq!(grid.to_string(), input)
     }
+
+    #[test]
+    fn should_reject_ragged_rows() {
+        let err = Grid::<char>::try_parse_with("123\n45\n678", |c| c).unwrap_err();
+
+        assert_eq!(err.line(), Some(2));
+        assert!(Grid::<char>::try_parse_with("123\n456\n\n", |c| c).is_ok());
+    }
+
+    #[test]
+    fn should_locate_bad_tiles() {
+        let digit = |c: char| c.to_digit(10).context("expected a digit");
+        let err = Grid::<u32>::try_parse_tiles("123\n4x6", digit).unwrap_err();
+
+        assert_eq!((err.line(), err.column()), (Some(2), Some(2)));
+        assert_eq!(err.message(), "expected a digit");
+        assert!(Grid::<u32>::try_parse_tiles("12\n34", digit).is_ok());
+    }
+
+    #[test]
+    fn should_handle_empty_grid() {
+        let grid = Grid::<char>::parse("");
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.at((0, 0).into()), None);
+        assert_eq!(grid.row_slices().count(), 0);
+        assert_eq!(grid.find_pt(|_| true), None);
+    }
+
+    #[test]
+    fn should_index_by_point() {
+        let mut grid = Grid::from_fn(3, 2, |pt| pt.row * 10 + pt.col);
+
+        assert_eq!(grid[Point::new(1, 2)], 12);
+        grid[Point::new(0, 1)] = 99;
+        *grid.at_mut((1, 0).into()).unwrap() += 1;
+
+        assert_eq!(grid.row(0), Some(&[0, 99, 2][..]));
+        assert_eq!(grid.row(1), Some(&[11, 11, 12][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.at_mut((2, 0).into()), None);
+        assert_eq!(grid.find_pt(|n| n == 11), Some(Point::new(1, 0)));
+    }
+
+    #[test]
+    fn should_enumerate_points() {
+        let mut grid = Grid::<char>::parse("a.b\n.a.");
+
+        assert_eq!(grid.iter_points().count(), 6);
+        assert_eq!(grid.enumerate().nth(4), Some((Point::new(1, 1), &'a')));
+        assert_eq!(
+            grid.positions_of(&'a').collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(1, 1)]
+        );
+        assert_eq!(
+            grid.find_all(|c| *c != '.').collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(0, 2), Point::new(1, 1)]
+        );
+
+        for (pt, tile) in grid.find_all_mut(|c| *c == '.') {
+            *tile = char::from_digit(pt.col as u32, 10).unwrap();
+        }
+        for (_, tile) in grid.enumerate_mut().filter(|(pt, _)| pt.row == 1) {
+            tile.make_ascii_uppercase();
+        }
+        assert_eq!(grid.to_string(), "a1b\n0A2");
+    }
+
+    #[test]
+    fn should_find_neighbors_in_bounds() {
+        let grid = Grid::<char>::parse("abc\ndef\nghi");
+        let tiles = |neighbors: Vec<(Point, &char)>| {
+            let mut tiles = neighbors.into_iter().map(|(_, c)| *c).collect::<Vec<_>>();
+            tiles.sort();
+            tiles.into_iter().collect::<String>()
+        };
+
+        assert_eq!(tiles(grid.neighbors4((1, 1).into()).collect()), "bdfh");
+        assert_eq!(tiles(grid.neighbors8((1, 1).into()).collect()), "abcdfghi");
+        assert_eq!(tiles(grid.neighbors4((0, 0).into()).collect()), "bd");
+        assert_eq!(tiles(grid.neighbors8((2, 2).into()).collect()), "efh");
+
+        let knight = [(1, 2), (2, 1), (-1, 2)].map(Point::from);
+        assert_eq!(
+            grid.neighbors_with((0, 0).into(), knight)
+                .collect::<Vec<_>>(),
+            vec![(Point::new(1, 2), &'f'), (Point::new(2, 1), &'h')]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the 3x2 grid")]
+    fn should_panic_when_indexing_outside() {
+        let grid = Grid::from_fn(3, 2, |_| 0);
+        let _ = grid[Point::new(0, 3)];
+    }
 }
@@ -2,3 +2,4 @@ pub mod dir;
 pub mod grid;
 pub mod point;
 pub mod puzzle;
+pub mod search;
@@ -1,36 +1,342 @@
 use clap::{Parser, ValueEnum};
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 use std::{
-    fs,
-    path::Path,
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process,
     time::{Duration, Instant},
 };
 
-#[derive(ValueEnum, Clone)]
-enum PuzzlePartName {
+mod answer;
+mod bench;
+mod bounds;
+mod error;
+mod expected;
+mod fetch;
+mod history;
+mod input;
+mod limits;
+mod output;
+mod page;
+mod params;
+mod registry;
+mod sink;
+mod testing;
+
+pub use answer::Answer;
+pub use bench::{BenchArgs, BenchRecord, BenchStats};
+pub use bounds::{Bounds, Verdict};
+pub use error::{catch_panics, Context, PuzzleError};
+pub use expected::{CheckOutcome, ExpectedAnswers};
+pub use fetch::{
+    validate_input, FetchError, FetchInput, HttpFetcher, InputCache, DEFAULT_BASE_URL,
+};
+pub use history::{History, HistoryEntry, Mark, HISTORY_FILE};
+pub use input::{example_names, InputError, InputKind, InputSource};
+pub use limits::{
+    can_track_memory, solve_with_timeout, track_memory, Bytes, Solver, TrackingAllocator,
+};
+pub use output::{ErrorRecord, OutputFormat, PartRecord};
+pub use page::PuzzlePage;
+pub use params::{ParamSettings, PuzzleParams};
+pub use registry::{DaySelection, Registry};
+pub use sink::{AnswerSink, SolvedAnswer, DEFAULT_SINKS};
+pub use testing::assert_example_answer;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PuzzlePartName {
+    #[serde(alias = "a")]
     A,
+    #[serde(alias = "b")]
     B,
 }
 
-#[derive(Parser)]
-struct PuzzleArgs {
+impl std::fmt::Display for PuzzlePartName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzlePartName::A => f.write_str("A"),
+            PuzzlePartName::B => f.write_str("B"),
+        }
+    }
+}
+
+#[derive(Parser, Clone, Debug)]
+pub struct PuzzleArgs {
     /// Which part of the puzzle to solve
     #[arg(long, short)]
-    part: Option<PuzzlePartName>,
+    pub part: Option<PuzzlePartName>,
+
+    /// Use an example input instead of the main input: the one called NAME in
+    /// `data/examples`, or (without a name) the first example meant for each part
+    #[arg(long, short = 'e', value_name = "NAME", num_args = 0..=1)]
+    pub example: Option<Option<String>>,
+
+    /// Solve every example in `data/examples`, with each of the parts it's meant for
+    #[arg(long, conflicts_with = "example")]
+    pub all_examples: bool,
+
+    /// Solve every example meant for each part, checking their answers, and then
+    /// (if none of them failed) the main input, reporting each in a single line
+    #[arg(long, conflicts_with_all = ["example", "all_examples", "input"])]
+    pub verify: bool,
+
+    /// Read input from this file instead of the puzzle's `data` directory
+    /// (use `-` to read from stdin)
+    #[arg(long, short)]
+    pub input: Option<PathBuf>,
+
+    /// Check solutions against the expected answers in `data/answers.toml`,
+    /// exiting with an error if any of them don't match
+    #[arg(long, short)]
+    pub check: bool,
+
+    /// How to print results
+    #[arg(long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+
+    /// Override one of the puzzle's parameters, eg. `--param size=71` (can be repeated)
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+    pub params: Vec<(String, String)>,
+
+    /// Where to put the answers to the main input, besides printing them
+    /// [default: file,history]
+    #[arg(long = "sink", value_enum, value_delimiter = ',')]
+    pub sinks: Vec<AnswerSink>,
+
+    /// Also copy the answers to the main input to the clipboard (same as adding `--sink clipboard`)
+    #[arg(long)]
+    pub copy: bool,
+
+    /// Give up on any part that takes longer than this many seconds to solve
+    /// (it keeps running in the background, which can slow down later parts)
+    #[arg(long, value_name = "SECS", value_parser = limits::parse_timeout)]
+    pub timeout: Option<Duration>,
+
+    /// Report the most memory that each part had allocated at once while solving
+    /// (on the solving thread only)
+    #[arg(long)]
+    pub memory: bool,
+}
+
+impl PuzzleArgs {
+    /// Whether example input is being solved, which decides the default parameters.
+    pub fn is_example(&self) -> bool {
+        self.example.is_some() || self.all_examples
+    }
+
+    /// Makes sure that these args make sense together, and with this binary,
+    /// when solving the given number of days.
+    pub fn validate(&self, days: usize) -> Result<(), String> {
+        if self.reads_stdin() && days != 1 {
+            return Err(format!(
+                "--input - can only be used with a single day, but {days} were selected"
+            ));
+        }
+        if self.memory && !can_track_memory() {
+            return Err(
+                "--memory needs a `TrackingAllocator` to be installed as the global allocator"
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Whether the input is piped in, which can only be read once.
+    pub fn reads_stdin(&self) -> bool {
+        self.input.as_deref() == Some(Path::new("-"))
+    }
+
+    /// Whether solutions should be checked against the expected answers.
+    pub fn is_checking(&self) -> bool {
+        self.check || self.verify
+    }
+
+    /// The parts of the puzzle to solve.
+    pub fn parts(&self) -> Vec<PuzzlePartName> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![PuzzlePartName::A, PuzzlePartName::B],
+        }
+    }
+
+    /// Works out which input to solve each selected part with. When using each part's
+    /// default example, a part without one is paired with `None`, and should be skipped.
+    pub fn inputs(
+        &self,
+        data_dir: &Path,
+        answers: &ExpectedAnswers,
+    ) -> Result<Vec<(PuzzlePartName, Option<InputKind>)>, InputError> {
+        let parts = self.parts();
+        let with_all_parts = |kind: InputKind| {
+            parts
+                .iter()
+                .map(|&part| (part, Some(kind.clone())))
+                .collect()
+        };
+
+        if let Some(path) = &self.input {
+            return Ok(with_all_parts(InputKind::Custom(path.clone())));
+        }
 
-    /// Whether to use example input instead of the main input
-    #[arg(long, short = 'e')]
-    example: bool,
+        if self.verify {
+            let names = match example_names(data_dir) {
+                Err(InputError::NoExamples(_)) => vec![],
+                names => names?,
+            };
+            return Ok(parts
+                .iter()
+                .flat_map(|&part| {
+                    names
+                        .iter()
+                        .filter(move |name| answers.example_is_for(name, part))
+                        .map(|name| InputKind::Example(name.clone()))
+                        .chain([InputKind::Real])
+                        .map(move |kind| (part, Some(kind)))
+                })
+                .collect());
+        }
+
+        match &self.example {
+            None if self.all_examples => Ok(example_names(data_dir)?
+                .iter()
+                .flat_map(|name| {
+                    parts
+                        .iter()
+                        .filter(|&&part| answers.example_is_for(name, part))
+                        .map(|&part| (part, Some(InputKind::Example(name.clone()))))
+                })
+                .collect()),
+            None => Ok(with_all_parts(InputKind::Real)),
+            Some(Some(name)) => Ok(with_all_parts(InputKind::Example(name.clone()))),
+            Some(None) => {
+                let names = example_names(data_dir)?;
+                Ok(parts
+                    .iter()
+                    .map(|&part| {
+                        let name = names.iter().find(|name| answers.example_is_for(name, part));
+                        (part, name.map(|name| InputKind::Example(name.clone())))
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Command line arguments that parse back into these args (apart from `--format`),
+    /// for passing them on to a puzzle's own binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(part) = self.part {
+            args.push(format!("--part={}", part.to_string().to_lowercase()));
+        }
+        match &self.example {
+            Some(Some(name)) => args.push(format!("--example={name}")),
+            Some(None) => args.push("--example".into()),
+            None => {}
+        }
+        if self.all_examples {
+            args.push("--all-examples".into());
+        }
+        if self.verify {
+            args.push("--verify".into());
+        }
+        if let Some(path) = &self.input {
+            args.push(format!("--input={}", path.display()));
+        }
+        if self.check {
+            args.push("--check".into());
+        }
+        for (name, value) in &self.params {
+            args.push(format!("--param={name}={value}"));
+        }
+        for sink in &self.sinks {
+            args.push(format!(
+                "--sink={}",
+                sink.to_possible_value().unwrap().get_name()
+            ));
+        }
+        if self.copy {
+            args.push("--copy".into());
+        }
+        if let Some(timeout) = self.timeout {
+            args.push(format!("--timeout={}", timeout.as_secs_f64()));
+        }
+        if self.memory {
+            args.push("--memory".into());
+        }
+
+        args
+    }
+
+    /// Where answers to the main input should go, which is `DEFAULT_SINKS`
+    /// unless some sinks were asked for.
+    pub fn answer_sinks(&self) -> Vec<AnswerSink> {
+        let mut sinks = match self.sinks.is_empty() {
+            true => DEFAULT_SINKS.to_vec(),
+            false => self.sinks.clone(),
+        };
+        if self.copy && !sinks.contains(&AnswerSink::Clipboard) {
+            sinks.push(AnswerSink::Clipboard);
+        }
+        sinks
+    }
+
+    /// Which parameters to pass to the solvers for the given input.
+    pub fn param_settings(&self, kind: &InputKind) -> ParamSettings {
+        let example = match kind {
+            InputKind::Example(_) => true,
+            InputKind::Real => false,
+            InputKind::Custom(_) => self.is_example(),
+        };
+
+        ParamSettings {
+            example,
+            overrides: self.params.clone(),
+        }
+    }
+}
+
+/// Reads each of the inputs that will be solved (just once, in case one is stdin).
+fn read_inputs(
+    data_dir: &Path,
+    jobs: &[(PuzzlePartName, Option<InputKind>)],
+) -> Result<HashMap<InputKind, String>, InputError> {
+    let mut inputs = HashMap::new();
+
+    for kind in jobs.iter().filter_map(|(_, kind)| kind.as_ref()) {
+        if !inputs.contains_key(kind) {
+            let input =
+                InputSource::of(data_dir, kind)
+                    .read()
+                    .map_err(|err| match (err, kind) {
+                        (InputError::Missing(_), InputKind::Example(name)) => {
+                            InputError::NoSuchExample(
+                                name.clone(),
+                                data_dir.join(input::EXAMPLES_DIR),
+                            )
+                        }
+                        (err, _) => err,
+                    })?;
+            inputs.insert(kind.clone(), input);
+        }
+    }
+
+    Ok(inputs)
 }
 
 pub trait PuzzlePart {
+    /// Values the solver needs beyond the input itself, like the size of a grid.
+    /// Most puzzles don't need any, so this is usually `()`.
+    type Params: PuzzleParams;
+
     /// A description of the value(s) computed for this puzzle,
     /// just for the sake of more meaningful output.
     fn description() -> &'static str;
 
     /// Do all of the work necessary to transform the input text into
-    /// the solution text.
-    fn solve(input: &str) -> String;
+    /// the solution, or explain what went wrong.
+    fn solve(input: &str, params: &Self::Params) -> Result<Answer, PuzzleError>;
 }
 
 pub trait Puzzle {
@@ -40,56 +346,394 @@ pub trait Puzzle {
     /// The name of the puzzle, usually just the number (eg. "01")
     fn name() -> &'static str;
 
+    /// Which year's Advent of Code the puzzle is from
+    fn year() -> u32;
+
+    /// Bundles up this puzzle's parts into a `PuzzleEntry`, which can be
+    /// stored in a `Registry` alongside every other day's puzzle.
+    fn entry() -> PuzzleEntry {
+        PuzzleEntry {
+            name: Self::name(),
+            year: Self::year(),
+            part_a: PartEntry::of::<Self::PartA>(),
+            part_b: PartEntry::of::<Self::PartB>(),
+        }
+    }
+
     /// Based on command line args, this executes the solver for one or both
-    /// parts of a day's puzzles, using either the primary input or the example input.
-    fn run(input: &str, example: &str) {
+    /// parts of a day's puzzles, using either the primary input or example input(s).
+    /// Input files are read at runtime from `data_dir` unless `--input` says otherwise.
+    fn run(data_dir: impl AsRef<Path>) {
         let args = PuzzleArgs::parse();
+        let data_dir = data_dir.as_ref();
+        args.format.configure();
+
+        let exit_with_error = |err: &dyn std::fmt::Display| -> ! {
+            eprintln!("{} {err}", "Error:".red().bold());
+            process::exit(1);
+        };
 
-        let input = if args.example { example } else { input };
+        args.validate(1).unwrap_or_else(|err| exit_with_error(&err));
+        let answers = ExpectedAnswers::load(data_dir).unwrap_or_else(|err| exit_with_error(&err));
 
-        match args.part {
-            Some(PuzzlePartName::A) => Self::process::<Self::PartA>("A", input, args.example, true),
-            Some(PuzzlePartName::B) => Self::process::<Self::PartB>("B", input, args.example, true),
-            None => {
-                Self::process::<Self::PartA>("A", input, args.example, false);
-                println!("{}", "---".dimmed());
-                Self::process::<Self::PartB>("B", input, args.example, false);
+        let entry = Self::entry();
+        entry
+            .check_params(args.part, &args.params)
+            .unwrap_or_else(|err| exit_with_error(&err));
+
+        let results = entry
+            .run(&args, data_dir, &answers)
+            .unwrap_or_else(|err| exit_with_error(&err));
+
+        if results.iter().any(PartResult::failed) {
+            process::exit(1);
+        }
+    }
+}
+
+/// The functions that make up a `PuzzlePart`, without the type attached.
+#[derive(Clone, Copy)]
+pub struct PartEntry {
+    pub description: fn() -> &'static str,
+    pub param_names: fn() -> &'static [&'static str],
+    pub solve: Solver,
+}
+
+impl PartEntry {
+    pub fn of<P: PuzzlePart>() -> Self {
+        Self {
+            description: P::description,
+            param_names: P::Params::names,
+            solve: |input, settings| P::solve(input, &settings.resolve()?),
+        }
+    }
+}
+
+/// A type-erased `Puzzle`, so that different days' puzzles can be
+/// enumerated and run through the same code.
+#[derive(Clone, Copy)]
+pub struct PuzzleEntry {
+    pub name: &'static str,
+    pub year: u32,
+    pub part_a: PartEntry,
+    pub part_b: PartEntry,
+}
+
+/// The outcome of solving one part of one puzzle.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub part: PuzzlePartName,
+    pub input: InputKind,
+    pub solution: Result<Answer, PuzzleError>,
+    pub duration: Duration,
+
+    /// How the solution compared to the expected answer, if it was checked
+    pub check: Option<CheckOutcome>,
+}
+
+impl PartResult {
+    /// Whether the solver failed, or its solution didn't match the expected answer.
+    pub fn failed(&self) -> bool {
+        self.solution.is_err() || matches!(self.check, Some(CheckOutcome::Fail { .. }))
+    }
+}
+
+impl PuzzleEntry {
+    /// The day number of this puzzle, if its name is a number.
+    pub fn day(&self) -> Option<u32> {
+        self.name.parse().ok()
+    }
+
+    pub fn part(&self, part: PuzzlePartName) -> &PartEntry {
+        match part {
+            PuzzlePartName::A => &self.part_a,
+            PuzzlePartName::B => &self.part_b,
+        }
+    }
+
+    /// Makes sure that every parameter override is for a parameter of the
+    /// selected part (or either part, if none is selected).
+    pub fn check_params(
+        &self,
+        part: Option<PuzzlePartName>,
+        overrides: &[(String, String)],
+    ) -> Result<(), String> {
+        let mut names = match part {
+            Some(part) => (self.part(part).param_names)().to_vec(),
+            None => [(self.part_a.param_names)(), (self.part_b.param_names)()].concat(),
+        };
+        names.sort_unstable();
+        names.dedup();
+
+        match overrides
+            .iter()
+            .find(|(name, _)| !names.contains(&name.as_str()))
+        {
+            None => Ok(()),
+            Some((name, _)) if names.is_empty() => Err(format!(
+                "unknown parameter '{name}' (this puzzle doesn't take any)"
+            )),
+            Some((name, _)) => Err(format!(
+                "unknown parameter '{name}' (expected one of: {})",
+                names.join(", ")
+            )),
+        }
+    }
+
+    /// Solves the part(s) selected by `args`, with the input(s) they select from
+    /// `data_dir`, printing the results as it goes. Answers to the main input are
+    /// also recorded by each of the sinks selected by `args`, after warning about
+    /// any that the marks in the puzzle's history rule out. With `--check` or
+    /// `--verify`, each solution is checked against `answers`, and with `--verify`,
+    /// a part whose examples failed isn't solved with the main input.
+    pub fn run(
+        &self,
+        args: &PuzzleArgs,
+        data_dir: &Path,
+        answers: &ExpectedAnswers,
+    ) -> Result<Vec<PartResult>, InputError> {
+        let jobs = args.inputs(data_dir, answers)?;
+
+        // with `--verify`, a missing main input shouldn't stop the examples being checked
+        let mut missing_input = None;
+        let inputs = match read_inputs(data_dir, &jobs) {
+            Err(err @ InputError::Missing(_)) if args.verify => {
+                missing_input = Some(err.to_string());
+                let example_jobs = jobs
+                    .iter()
+                    .filter(|(_, kind)| *kind != Some(InputKind::Real))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                read_inputs(data_dir, &example_jobs)?
             }
+            inputs => inputs?,
         };
+
+        let mut results: Vec<PartResult> = vec![];
+        for (idx, (part, kind)) in jobs.iter().enumerate() {
+            if idx > 0 && args.format.is_human() && !args.verify {
+                println!("{}", "---".dimmed());
+            }
+
+            let examples_failed = || {
+                results
+                    .iter()
+                    .any(|result| result.part == *part && result.failed())
+            };
+
+            let kind = match (kind, &missing_input) {
+                (Some(InputKind::Real), Some(msg)) => Err(msg.as_str()),
+                (Some(InputKind::Real), _) if args.verify && examples_failed() => {
+                    Err("an example failed")
+                }
+                (Some(kind), _) => Ok(kind),
+                (None, _) => Err("no example is meant for it"),
+            };
+
+            match kind {
+                Ok(kind) => {
+                    let result = self.process(*part, kind, &inputs[kind], args, data_dir, answers);
+                    results.push(result);
+                }
+                Err(reason) if args.format.is_human() => print_skipped(self.name, *part, reason),
+                Err(_) => {}
+            }
+        }
+
+        Ok(results)
     }
 
-    fn process<P: PuzzlePart>(part_name: &str, input: &str, is_example: bool, write_to_file: bool) {
-        print_puzzle_info::<P>(Self::name(), part_name, is_example);
+    fn process(
+        &self,
+        part: PuzzlePartName,
+        kind: &InputKind,
+        input: &str,
+        args: &PuzzleArgs,
+        data_dir: &Path,
+        answers: &ExpectedAnswers,
+    ) -> PartResult {
+        let entry = self.part(part);
+        let description = (entry.description)();
+        if args.format.is_human() && !args.verify {
+            print_puzzle_info(self.name, part, description, kind);
+        }
 
         let start_time = Instant::now();
-        let solution = <P as PuzzlePart>::solve(input);
+        let params = args.param_settings(kind);
+        let (solution, peak_memory) =
+            solve_with_timeout(entry.solve, input, &params, args.timeout, args.memory);
         let duration = start_time.elapsed();
 
-        print_results(&solution, duration);
+        let check = args
+            .is_checking()
+            .then(|| answers.check(part, kind, &solution));
+
+        // answers to the main input are judged against the marks in the puzzle's history
+        let solved = solution.as_ref().ok().filter(|s| !s.is_unsolved());
+        let real_answer = solved.filter(|_| *kind == InputKind::Real);
+        let verdict = real_answer.and_then(|answer| match History::load(data_dir) {
+            Ok(history) => Some(Bounds::new(&history, part).judge(answer)),
+            Err(msg) => {
+                eprintln!("{} {msg}", "Warning:".yellow().bold());
+                None
+            }
+        });
+        let plausible = verdict.as_ref().is_none_or(Verdict::is_plausible);
+
+        if args.format.is_human() && args.verify {
+            print_compact(self.name, part, kind, &solution, duration, check.as_ref());
+        } else if args.format.is_human() {
+            match &solution {
+                Ok(solution) => print_results(solution, duration),
+                Err(err) => print_error(err),
+            }
+            if let Some(peak) = peak_memory {
+                print_memory(peak);
+            }
+            if let Some(check) = &check {
+                print_check(check);
+            }
+        }
+
+        if let (Some(answer), Some(verdict)) = (real_answer, &verdict) {
+            if !plausible {
+                print_implausible(answer, verdict);
+            }
+        }
+
+        args.format.emit(&PartRecord {
+            year: self.year,
+            day: self.name,
+            part,
+            description,
+            input_kind: kind.label(),
+            example: kind.example_name(),
+            answer: solution.as_ref().unwrap_or(&Answer::Unsolved),
+            error: solution.as_ref().err().map(ToString::to_string),
+            duration_ns: duration.as_nanos(),
+            peak_memory_bytes: peak_memory.map(|peak| peak.0),
+            check: check.as_ref(),
+            verdict: verdict.as_ref(),
+        });
+
+        if let Some(answer) = real_answer {
+            let solved = SolvedAnswer {
+                part,
+                answer,
+                duration,
+            };
+            for sink in args.answer_sinks() {
+                // an answer that can't be right shouldn't end up where it might be submitted
+                if !plausible && sink.is_for_submitting() {
+                    continue;
+                }
+                if let Err(msg) = sink.record(data_dir, &solved, args.format) {
+                    eprintln!("{} {msg}", "Warning:".yellow().bold());
+                }
+            }
+        }
 
-        if write_to_file && Path::new("data").exists() {
-            fs::write(format!("data/soln-{}", part_name.to_lowercase()), solution).unwrap();
+        PartResult {
+            part,
+            input: kind.clone(),
+            solution,
+            duration,
+            check,
         }
     }
 }
 
-fn print_puzzle_info<P: PuzzlePart>(puzzle_name: &str, part_name: &str, is_example: bool) {
-    let puzzle_name = format!("Puzzle {} Part {part_name}", puzzle_name).blue();
-    let example_note = if is_example {
-        format!(" ({})", "example input".yellow())
-    } else {
-        "".into()
+fn print_puzzle_info(puzzle_name: &str, part: PuzzlePartName, description: &str, kind: &InputKind) {
+    let puzzle_name = format!("Puzzle {} Part {part}", puzzle_name).blue();
+    let example_note = match kind {
+        InputKind::Example(name) => format!(" ({})", format!("example {name}").yellow()),
+        _ => "".into(),
     };
     println!("Solving {puzzle_name}{example_note}:");
 
-    let description = format!("\"{}\"", <P as PuzzlePart>::description()).dimmed();
+    let description = format!("\"{description}\"").dimmed();
     println!("{description}");
 }
 
-fn print_results(solution: &str, duration: Duration) {
+fn print_skipped(puzzle_name: &str, part: PuzzlePartName, reason: &str) {
+    let puzzle_name = format!("Puzzle {} Part {part}", puzzle_name).blue();
+    println!("Skipping {puzzle_name}: {}", reason.yellow());
+}
+
+/// Prints the outcome of solving a part in a single line, for `--verify`.
+fn print_compact(
+    puzzle_name: &str,
+    part: PuzzlePartName,
+    kind: &InputKind,
+    solution: &Result<Answer, PuzzleError>,
+    duration: Duration,
+    check: Option<&CheckOutcome>,
+) {
+    let input = match kind {
+        InputKind::Example(name) => format!("example {name}"),
+        _ => kind.label().to_string(),
+    };
+    let mark = match check {
+        Some(CheckOutcome::Pass) => "✔".green(),
+        Some(CheckOutcome::Fail { .. }) => "✘".red(),
+        Some(CheckOutcome::Unknown) | None => " ".normal(),
+    };
+    let outcome = match solution {
+        Ok(Answer::Multiline(s)) => format!("\n{s}").magenta(),
+        Ok(Answer::Unsolved) => "Unsolved!".yellow(),
+        Ok(solution) => solution.to_string().magenta(),
+        Err(err) => format!("failed: {err}").red(),
+    };
+    let expected = match check {
+        Some(CheckOutcome::Fail { expected }) => format!(" (expected {expected})").yellow(),
+        _ => "".normal(),
+    };
+
+    println!(
+        "{} {input:<12} {mark} {outcome}{expected} {}",
+        format!("Puzzle {puzzle_name} Part {part}").blue(),
+        format!("(in {duration:?})").dimmed()
+    );
+}
+
+/// Warns that an answer can't be right, and why.
+fn print_implausible(answer: &Answer, verdict: &Verdict) {
+    eprintln!(
+        "{} {answer} can't be right: {verdict} (so it wasn't saved or copied)",
+        "Implausible:".red().bold()
+    );
+}
+
+fn print_results(solution: &Answer, duration: Duration) {
+    let solution = match solution {
+        Answer::Unsolved => solution.to_string().yellow(),
+        Answer::Multiline(s) => format!("\n{s}").magenta(),
+        _ => solution.to_string().magenta(),
+    };
+
     println!(
         "{} {}",
-        format!("Solution: {}", solution.magenta()).bold(),
+        format!("Solution: {solution}").bold(),
         format!("(in {})", format!("{duration:?}").green()).dimmed()
     );
 }
+
+fn print_memory(peak: Bytes) {
+    println!("{}", format!("(peak memory: {peak})").dimmed());
+}
+
+fn print_error(err: &PuzzleError) {
+    println!("{} {}", "Failed:".red().bold(), err.to_string().red());
+}
+
+fn print_check(check: &CheckOutcome) {
+    match check {
+        CheckOutcome::Pass => println!("{}", "✔ Matches the expected answer".green()),
+        CheckOutcome::Fail { expected } => println!(
+            "{} {}",
+            "✘ Does not match the expected answer:".red().bold(),
+            expected.yellow()
+        ),
+        CheckOutcome::Unknown => println!("{}", "? No expected answer recorded".yellow()),
+    }
+}
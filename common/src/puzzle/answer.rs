@@ -0,0 +1,198 @@
+use crate::grid::Grid;
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// The solution to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// Most puzzles' answers are a single number
+    Integer(i128),
+
+    /// A single line of text, like a comma-separated list
+    Text(String),
+
+    /// Several lines of text, like a picture drawn on a grid
+    Multiline(String),
+
+    /// The puzzle hasn't been solved yet
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_unsolved(&self) -> bool {
+        *self == Answer::Unsolved
+    }
+
+    /// Whether this answer is the same as an expected answer written as text.
+    /// An unsolved answer never matches anything.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Unsolved => false,
+            _ => self.to_string().trim() == expected.trim(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => f.pad(&n.to_string()),
+            Answer::Text(s) | Answer::Multiline(s) => f.pad(s),
+            Answer::Unsolved => f.pad("Unsolved!"),
+        }
+    }
+}
+
+/// Integers are written as JSON numbers, text as strings, and unsolved answers as `null`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(n) => serializer.serialize_i128(*n),
+            Answer::Text(s) | Answer::Multiline(s) => serializer.serialize_str(s),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Reads answers written by `Serialize`, so numbers become integers and `null` is unsolved.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an integer, a string or null")
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_i128<E: de::Error>(self, n: i128) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+                Ok(s.into())
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Answer, E> {
+                Ok(Answer::Unsolved)
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+/// Anything that looks like an integer is one, and everything else is text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.trim().parse().map_or_else(|_| s.into(), Answer::Integer))
+    }
+}
+
+// makes it quicker/cleaner to convert all of the integer types
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.trim_end().contains('\n') {
+            Answer::Multiline(s)
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        s.to_string().into()
+    }
+}
+
+impl<T> From<&Grid<T>> for Answer
+where
+    T: Into<char> + Clone,
+{
+    fn from(grid: &Grid<T>) -> Self {
+        Answer::Multiline(grid.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_from_numbers() {
+        assert_eq!(Answer::from(41_u64), Answer::Integer(41));
+        assert_eq!(Answer::from(-3_i64), Answer::Integer(-3));
+        assert_eq!(Answer::from(7_usize).to_string(), "7");
+    }
+
+    #[test]
+    fn should_convert_from_text() {
+        assert_eq!(
+            Answer::from("co,de,ka,ta"),
+            Answer::Text("co,de,ka,ta".into())
+        );
+        assert_eq!(Answer::from("#.\n.#"), Answer::Multiline("#.\n.#".into()));
+    }
+
+    #[test]
+    fn should_match_expected_answers() {
+        assert!(Answer::from(41_u32).matches("41"));
+        assert!(Answer::from("5,7,3,0").matches("5,7,3,0\n"));
+        assert!(!Answer::from(41_u32).matches("42"));
+        assert!(!Answer::Unsolved.matches("Unsolved!"));
+    }
+
+    #[test]
+    fn should_serialize_by_kind() {
+        let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+
+        assert_eq!(json(Answer::Integer(41)), "41");
+        assert_eq!(json(Answer::from("6,1")), "\"6,1\"");
+        assert_eq!(json(Answer::Unsolved), "null");
+    }
+
+    #[test]
+    fn should_read_back_serialized_answers() {
+        for answer in [
+            Answer::Integer(-41),
+            Answer::from("6,1"),
+            Answer::from("#.\n.#"),
+            Answer::Unsolved,
+        ] {
+            let json = serde_json::to_string(&answer).unwrap();
+            assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+        }
+
+        assert_eq!("41".parse(), Ok(Answer::Integer(41)));
+        assert_eq!("co,de".parse(), Ok(Answer::from("co,de")));
+    }
+}
@@ -0,0 +1,255 @@
+use super::{
+    catch_panics, limits, Answer, InputKind, ParamSettings, PartEntry, PuzzleError, PuzzlePartName,
+};
+use clap::Args;
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant},
+};
+
+#[derive(Args, Clone, Debug)]
+pub struct BenchArgs {
+    /// How many timed runs of each part to do
+    #[arg(long, short = 'n', default_value_t = 10)]
+    pub runs: usize,
+
+    /// How many untimed runs of each part to do before the timed ones
+    #[arg(long, short, default_value_t = 2)]
+    pub warmup: usize,
+
+    /// Write the results to this file, as JSON or CSV depending on its extension
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+/// Summary statistics over the timings of many runs of a solver.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    /// Computes statistics for a (non-empty) list of timings.
+    pub fn from_timings(timings: &[Duration]) -> Self {
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let nanos = sorted
+            .iter()
+            .map(|t| t.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Solves a part `args.warmup` times without timing, then `args.runs` times
+/// with timing. Returns the last solution along with the timing statistics,
+/// or the error from the first run if the solver fails. With a `timeout`, the
+/// runs happen on a worker thread, and any run taking longer than that fails.
+pub fn bench_part(
+    entry: &PartEntry,
+    input: &str,
+    params: &ParamSettings,
+    args: &BenchArgs,
+    timeout: Option<Duration>,
+) -> Result<(Answer, BenchStats), PuzzleError> {
+    let total_runs = args.warmup + args.runs.max(1);
+    let solve = entry.solve;
+    let (input, params) = (input.to_string(), params.clone());
+    let timed_run = move || {
+        let start_time = Instant::now();
+        let solution = catch_panics(|| solve(&input, &params))?;
+        Ok((solution, start_time.elapsed()))
+    };
+
+    let runs = match timeout {
+        Some(timeout) => limits::run_on_worker(total_runs, timeout, timed_run)?,
+        None => (0..total_runs)
+            .map(|_| timed_run())
+            .collect::<Result<Vec<_>, PuzzleError>>()?,
+    };
+
+    let timings = runs[args.warmup..]
+        .iter()
+        .map(|(_, timing)| *timing)
+        .collect::<Vec<_>>();
+    let (solution, _) = runs.into_iter().last().expect("there is at least one run");
+
+    Ok((solution, BenchStats::from_timings(&timings)))
+}
+
+/// One row of benchmark output, as written to a results file.
+#[derive(Clone, Debug, Serialize)]
+pub struct BenchRecord {
+    pub year: u32,
+    pub day: String,
+    pub part: String,
+    pub input_kind: &'static str,
+    pub example: Option<String>,
+    pub profile: &'static str,
+    pub commit: Option<String>,
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub stddev_ns: u128,
+}
+
+impl BenchRecord {
+    pub fn new(
+        year: u32,
+        day: &str,
+        part: PuzzlePartName,
+        input: &InputKind,
+        runs: usize,
+        stats: &BenchStats,
+        commit: Option<String>,
+    ) -> Self {
+        Self {
+            year,
+            day: day.into(),
+            part: part.to_string(),
+            input_kind: input.label(),
+            example: input.example_name().map(ToString::to_string),
+            profile: build_profile(),
+            commit,
+            runs,
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            stddev_ns: stats.stddev.as_nanos(),
+        }
+    }
+}
+
+/// Writes benchmark records to `path`, as CSV if it ends in `.csv` and JSON otherwise.
+pub fn write_records(path: &Path, records: &[BenchRecord]) -> Result<(), String> {
+    let contents = if path.extension().is_some_and(|ext| ext == "csv") {
+        to_csv(records)
+    } else {
+        serde_json::to_string_pretty(records).map_err(|err| err.to_string())?
+    };
+
+    fs::write(path, contents).map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+fn to_csv(records: &[BenchRecord]) -> String {
+    let mut csv =
+        "year,day,part,input_kind,example,profile,commit,runs,min_ns,median_ns,mean_ns,stddev_ns\n"
+            .to_string();
+
+    for r in records {
+        csv += &format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            r.year,
+            r.day,
+            r.part,
+            r.input_kind,
+            r.example.as_deref().unwrap_or(""),
+            r.profile,
+            r.commit.as_deref().unwrap_or(""),
+            r.runs,
+            r.min_ns,
+            r.median_ns,
+            r.mean_ns,
+            r.stddev_ns
+        );
+    }
+
+    csv
+}
+
+/// Whether this binary was built in debug or release mode.
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// The short hash of the currently checked-out git commit, if there is one.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn should_compute_bench_stats() {
+        let stats = BenchStats::from_timings(&millis(&[4, 2, 6, 8]));
+
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        // population stddev of [2, 4, 6, 8] is sqrt(5)
+        assert_eq!(stats.stddev, Duration::from_nanos(2_236_068));
+    }
+
+    #[test]
+    fn should_compute_stats_for_single_run() {
+        let stats = BenchStats::from_timings(&millis(&[3]));
+
+        assert_eq!(stats.min, Duration::from_millis(3));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn should_write_csv() {
+        let stats = BenchStats::from_timings(&millis(&[1]));
+        let record = BenchRecord::new(
+            2024,
+            "06",
+            PuzzlePartName::B,
+            &InputKind::Example("2".into()),
+            1,
+            &stats,
+            Some("abc1234".into()),
+        );
+
+        let csv = to_csv(&[record]);
+        let row = csv.lines().nth(1).unwrap();
+        let profile = build_profile();
+
+        assert_eq!(
+            row,
+            format!("2024,06,B,example,2,{profile},abc1234,1,1000000,1000000,1000000,0")
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use super::{Answer, History, Mark, PuzzlePartName};
+use serde::Serialize;
+use std::fmt;
+
+/// What's known about a part's answer from the marks in its history: the answer
+/// that was accepted, the ones that were rejected, and the interval given by the
+/// answers that were too high or too low.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bounds {
+    accepted: Option<Answer>,
+    rejected: Vec<(Answer, Mark)>,
+    /// The answer must be greater than this, which was too low
+    above: Option<i128>,
+    /// The answer must be less than this, which was too high
+    below: Option<i128>,
+}
+
+/// How a candidate answer would fare if it were submitted, as far as can be told offline.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
+pub enum Verdict {
+    /// It's the answer that was accepted
+    Correct,
+
+    /// A different answer was accepted
+    Wrong { accepted: Answer },
+
+    /// It was submitted before, and marked as wrong
+    AlreadyMarked { mark: Mark },
+
+    /// It isn't less than an answer that was too high
+    TooHigh { bound: i128 },
+
+    /// It isn't greater than an answer that was too low
+    TooLow { bound: i128 },
+
+    /// Nothing rules it out
+    Plausible,
+}
+
+impl Bounds {
+    /// The bounds on a part's answer, from the marks in `history`.
+    pub fn new(history: &History, part: PuzzlePartName) -> Self {
+        let mut bounds = Self::default();
+        for (answer, mark) in history.marks(part) {
+            match (mark, answer) {
+                (Mark::Accepted, _) => bounds.accepted = Some(answer.clone()),
+                (Mark::TooHigh, Answer::Integer(n)) => {
+                    bounds.below = Some(bounds.below.map_or(*n, |below| below.min(*n)));
+                }
+                (Mark::TooLow, Answer::Integer(n)) => {
+                    bounds.above = Some(bounds.above.map_or(*n, |above| above.max(*n)));
+                }
+                _ => {}
+            }
+            if mark != Mark::Accepted {
+                bounds.rejected.push((answer.clone(), mark));
+            }
+        }
+
+        bounds
+    }
+
+    /// Whether `answer` could be right, and if not, why not.
+    pub fn judge(&self, answer: &Answer) -> Verdict {
+        if let Some(accepted) = &self.accepted {
+            return match accepted == answer {
+                true => Verdict::Correct,
+                false => Verdict::Wrong {
+                    accepted: accepted.clone(),
+                },
+            };
+        }
+        if let Some((_, mark)) = self
+            .rejected
+            .iter()
+            .find(|(rejected, _)| rejected == answer)
+        {
+            return Verdict::AlreadyMarked { mark: *mark };
+        }
+
+        match *answer {
+            Answer::Integer(n) if self.below.is_some_and(|below| n >= below) => Verdict::TooHigh {
+                bound: self.below.unwrap(),
+            },
+            Answer::Integer(n) if self.above.is_some_and(|above| n <= above) => Verdict::TooLow {
+                bound: self.above.unwrap(),
+            },
+            _ => Verdict::Plausible,
+        }
+    }
+}
+
+/// Shows the interval, eg. `20 < answer < 70`.
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(accepted) = &self.accepted {
+            return write!(f, "answer = {accepted}");
+        }
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => write!(f, "{above} < answer < {below}"),
+            (Some(above), None) => write!(f, "answer > {above}"),
+            (None, Some(below)) => write!(f, "answer < {below}"),
+            (None, None) => f.write_str("no bounds"),
+        }
+    }
+}
+
+impl Verdict {
+    /// Whether the answer might be (or is) right.
+    pub fn is_plausible(&self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::Plausible)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("it was accepted"),
+            Verdict::Wrong { accepted } => write!(f, "{accepted} was accepted instead"),
+            Verdict::AlreadyMarked { mark } => write!(f, "it was already {mark}"),
+            Verdict::TooHigh { bound } => {
+                write!(f, "it must be less than {bound}, which was too high")
+            }
+            Verdict::TooLow { bound } => {
+                write!(f, "it must be greater than {bound}, which was too low")
+            }
+            Verdict::Plausible => f.write_str("nothing rules it out"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(marks: &[(i128, Mark)]) -> Bounds {
+        let lines = marks
+            .iter()
+            .map(|(n, mark)| {
+                let mark = serde_json::to_string(mark).unwrap();
+                format!(r#"{{"timestamp":1,"part":"A","answer":{n},"mark":{mark}}}"#)
+            })
+            .collect::<Vec<_>>();
+
+        Bounds::new(
+            &History::parse(&lines.join("\n")).unwrap(),
+            PuzzlePartName::A,
+        )
+    }
+
+    #[test]
+    fn should_judge_answers_against_bounds() {
+        let bounds = bounds(&[
+            (90, Mark::TooHigh),
+            (70, Mark::TooHigh),
+            (20, Mark::TooLow),
+            (45, Mark::Rejected),
+        ]);
+        let judge = |n: i128| bounds.judge(&Answer::Integer(n));
+
+        assert_eq!(bounds.to_string(), "20 < answer < 70");
+        assert_eq!(judge(44), Verdict::Plausible);
+        assert_eq!(judge(80), Verdict::TooHigh { bound: 70 });
+        assert_eq!(
+            judge(70),
+            Verdict::AlreadyMarked {
+                mark: Mark::TooHigh
+            }
+        );
+        assert_eq!(judge(3), Verdict::TooLow { bound: 20 });
+        assert_eq!(
+            judge(45),
+            Verdict::AlreadyMarked {
+                mark: Mark::Rejected
+            }
+        );
+        assert!(bounds.judge(&Answer::from("abc")).is_plausible());
+    }
+
+    #[test]
+    fn should_prefer_the_accepted_answer() {
+        let bounds = bounds(&[(90, Mark::TooHigh), (6, Mark::Accepted)]);
+
+        assert_eq!(bounds.to_string(), "answer = 6");
+        assert_eq!(bounds.judge(&Answer::Integer(6)), Verdict::Correct);
+        assert_eq!(
+            bounds.judge(&Answer::Integer(7)),
+            Verdict::Wrong {
+                accepted: Answer::Integer(6)
+            }
+        );
+    }
+}
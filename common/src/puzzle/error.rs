@@ -0,0 +1,249 @@
+use super::Answer;
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+/// Something that went wrong while solving a puzzle, usually because
+/// the input wasn't shaped the way the solver expected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleError {
+    message: String,
+
+    /// 1-based line number in the input where the problem was found
+    line: Option<usize>,
+
+    /// 1-based column number in the input where the problem was found
+    column: Option<usize>,
+}
+
+impl PuzzleError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Attaches a (1-based) line number to this error.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Attaches a (1-based) line and column number to this error.
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Attaches the position of `fragment` to this error, where `fragment` is
+    /// a slice of `input`. Does nothing if `fragment` isn't part of `input`.
+    pub fn at_fragment(self, input: &str, fragment: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+        if offset.saturating_add(fragment.len()) > input.len() {
+            return self;
+        }
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |idx| idx + 1) + 1;
+        self.at(line, column)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => {}
+        }
+
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+impl From<&str> for PuzzleError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for PuzzleError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<std::num::ParseIntError> for PuzzleError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        Self::new(format!("invalid number ({err})"))
+    }
+}
+
+/// Adds some context to a missing value or a failed operation, turning it into
+/// a `PuzzleError`. Makes it easy to use `?` in parsers:
+/// ```
+/// use common::puzzle::{Context, PuzzleError};
+///
+/// fn parse_size(line: &str) -> Result<(i64, i64), PuzzleError> {
+///     let (w, h) = line.split_once(',').context("expected a comma")?;
+///     Ok((w.parse().context("bad width")?, h.parse().context("bad height")?))
+/// }
+///
+/// assert!(parse_size("11,7").is_ok());
+/// assert_eq!(parse_size("11").unwrap_err().message(), "expected a comma");
+/// ```
+pub trait Context<T> {
+    fn context(self, message: impl Into<String>) -> Result<T, PuzzleError>;
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context(self, message: impl Into<String>) -> Result<T, PuzzleError> {
+        self.ok_or_else(|| PuzzleError::new(message))
+    }
+}
+
+impl<T, E: fmt::Display> Context<T> for Result<T, E> {
+    fn context(self, message: impl Into<String>) -> Result<T, PuzzleError> {
+        self.map_err(|err| PuzzleError::new(format!("{} ({err})", message.into())))
+    }
+}
+
+thread_local! {
+    /// Whether this thread is currently inside `catch_panics`.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+
+    /// Where the most recent panic inside `catch_panics` on this thread happened.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs (once per process) a panic hook that quietly records the location of
+/// panics raised inside `catch_panics`, and hands every other panic to whichever
+/// hook was installed before.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let prev_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(ToString::to_string);
+                PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+            } else {
+                prev_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs a solver, turning any panic into a `PuzzleError` rather than letting
+/// it print a backtrace and take down the whole runner.
+pub fn catch_panics<F>(solve: F) -> Result<Answer, PuzzleError>
+where
+    F: FnOnce() -> Result<Answer, PuzzleError>,
+{
+    install_panic_hook();
+
+    let was_catching = CATCHING.with(|cell| cell.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(solve));
+    CATCHING.with(|cell| cell.set(was_catching));
+
+    result.unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".into());
+
+        let location = PANIC_LOCATION.with(|cell| cell.borrow_mut().take());
+        Err(match location {
+            Some(location) => PuzzleError::new(format!("panicked at {location}: {message}")),
+            None => PuzzleError::new(format!("panicked: {message}")),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_display_location() {
+        assert_eq!(PuzzleError::new("oops").to_string(), "oops");
+        assert_eq!(
+            PuzzleError::new("oops").at_line(3).to_string(),
+            "line 3: oops"
+        );
+        assert_eq!(
+            PuzzleError::new("oops").at(3, 7).to_string(),
+            "line 3, column 7: oops"
+        );
+    }
+
+    #[test]
+    fn should_locate_fragment() {
+        let input = "abc\ndef\nghi";
+        let fragment = &input[9..];
+
+        let err = PuzzleError::new("oops").at_fragment(input, fragment);
+        assert_eq!((err.line(), err.column()), (Some(3), Some(2)));
+
+        let err = PuzzleError::new("oops").at_fragment(input, "elsewhere");
+        assert_eq!(err.line(), None);
+    }
+
+    #[test]
+    fn should_add_context() {
+        let missing: Option<u8> = None;
+        assert_eq!(missing.context("nothing").unwrap_err().message(), "nothing");
+
+        let bad = "x".parse::<u8>().context("bad number").unwrap_err();
+        assert!(bad.message().starts_with("bad number ("));
+    }
+
+    #[test]
+    fn should_catch_panics() {
+        let result = catch_panics(|| panic!("kaboom"));
+        let err = result.unwrap_err();
+
+        assert!(err.message().starts_with("panicked at "));
+        assert!(err.message().ends_with(": kaboom"));
+        assert_eq!(catch_panics(|| Ok(Answer::from(1))), Ok(Answer::Integer(1)));
+    }
+
+    #[test]
+    fn should_catch_panics_on_many_threads() {
+        let handles: Vec<_> = (0..8)
+            .map(|idx| {
+                std::thread::spawn(move || catch_panics(|| panic!("thread {idx}")).unwrap_err())
+            })
+            .collect();
+
+        for (idx, handle) in handles.into_iter().enumerate() {
+            let err = handle.join().unwrap();
+            assert!(err.message().starts_with("panicked at "));
+            assert!(err.message().ends_with(&format!(": thread {idx}")));
+        }
+    }
+}
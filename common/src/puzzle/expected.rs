@@ -0,0 +1,266 @@
+use super::{Answer, InputKind, PuzzleError, PuzzlePartName};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+/// The name of the file (in a puzzle's `data` directory) holding known-good answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-good answers for a puzzle, read from `data/answers.toml`, which looks like:
+/// ```toml
+/// [examples.1]
+/// a = 41
+/// b = 6
+///
+/// [examples.larger]
+/// parts = ["b"]
+///
+/// [input]
+/// a = "4758"
+/// ```
+/// Each `[examples.<name>]` table belongs to the example input `data/examples/<name>`.
+/// Any entry can be left out, in which case checking that part is inconclusive.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedAnswers {
+    #[serde(default)]
+    examples: BTreeMap<String, ExampleAnswers>,
+
+    #[serde(default)]
+    input: PartAnswers,
+}
+
+/// The answers for one example, which might only be meant for one of the parts.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+struct ExampleAnswers {
+    /// Which parts this example is for. If it's left out, the example is for
+    /// whichever parts have answers, or both if neither does.
+    parts: Option<Vec<PuzzlePartName>>,
+    a: Option<ExpectedAnswer>,
+    b: Option<ExpectedAnswer>,
+}
+
+impl ExampleAnswers {
+    fn answers(&self) -> PartAnswers {
+        PartAnswers {
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+struct PartAnswers {
+    a: Option<ExpectedAnswer>,
+    b: Option<ExpectedAnswer>,
+}
+
+impl PartAnswers {
+    fn get(&self, part: PuzzlePartName) -> Option<&ExpectedAnswer> {
+        match part {
+            PuzzlePartName::A => self.a.as_ref(),
+            PuzzlePartName::B => self.b.as_ref(),
+        }
+    }
+}
+
+/// Answers can be written in the file as either numbers or strings.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+enum ExpectedAnswer {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpectedAnswer::Int(n) => write!(f, "{n}"),
+            ExpectedAnswer::Str(s) => f.write_str(s),
+        }
+    }
+}
+
+impl ExpectedAnswers {
+    /// Reads the answers file from `data_dir`. A missing file is fine (every
+    /// answer is just unknown), but a malformed one is an error.
+    pub fn load(data_dir: &Path) -> Result<Self, String> {
+        let path = data_dir.join(ANSWERS_FILE);
+
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|err| err.message().to_string())
+    }
+
+    /// The expected answer for one part of the puzzle, solved with the given input.
+    /// There's never an answer for a custom input.
+    pub fn get(&self, part: PuzzlePartName, input: &InputKind) -> Option<String> {
+        let answer = match input {
+            InputKind::Real => self.input.get(part).cloned(),
+            InputKind::Example(name) => self.examples.get(name)?.answers().get(part).cloned(),
+            InputKind::Custom(_) => None,
+        };
+
+        answer.map(|answer| answer.to_string())
+    }
+
+    /// Whether the example called `name` is meant to be solved by `part`.
+    pub fn example_is_for(&self, name: &str, part: PuzzlePartName) -> bool {
+        let Some(example) = self.examples.get(name) else {
+            return true;
+        };
+
+        match &example.parts {
+            Some(parts) => parts.contains(&part),
+            None if example.a.is_none() && example.b.is_none() => true,
+            None => example.answers().get(part).is_some(),
+        }
+    }
+
+    /// Compares a computed solution against the expected answer, if there is one.
+    /// A solver that failed never matches.
+    pub fn check(
+        &self,
+        part: PuzzlePartName,
+        input: &InputKind,
+        solution: &Result<Answer, PuzzleError>,
+    ) -> CheckOutcome {
+        match self.get(part, input) {
+            Some(expected) if solution.as_ref().is_ok_and(|s| s.matches(&expected)) => {
+                CheckOutcome::Pass
+            }
+            Some(expected) => CheckOutcome::Fail { expected },
+            None => CheckOutcome::Unknown,
+        }
+    }
+}
+
+/// The result of checking a solution against its expected answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum CheckOutcome {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[examples.1]
+a = 41
+b = "6"
+
+[examples.2]
+b = 16
+
+[examples.larger]
+parts = ["a"]
+
+[input]
+a = 4758
+"#;
+
+    fn example(name: &str) -> InputKind {
+        InputKind::Example(name.into())
+    }
+
+    #[test]
+    fn should_parse_answers_file() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.get(PuzzlePartName::A, &example("1")),
+            Some("41".into())
+        );
+        assert_eq!(
+            answers.get(PuzzlePartName::B, &example("1")),
+            Some("6".into())
+        );
+        assert_eq!(
+            answers.get(PuzzlePartName::B, &example("2")),
+            Some("16".into())
+        );
+        assert_eq!(answers.get(PuzzlePartName::A, &example("3")), None);
+        assert_eq!(
+            answers.get(PuzzlePartName::A, &InputKind::Real),
+            Some("4758".into())
+        );
+        assert_eq!(answers.get(PuzzlePartName::B, &InputKind::Real), None);
+        assert_eq!(
+            answers.get(PuzzlePartName::A, &InputKind::Custom("x".into())),
+            None
+        );
+    }
+
+    #[test]
+    fn should_work_out_which_parts_examples_are_for() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+
+        assert!(answers.example_is_for("1", PuzzlePartName::A));
+        assert!(answers.example_is_for("1", PuzzlePartName::B));
+        assert!(!answers.example_is_for("2", PuzzlePartName::A));
+        assert!(answers.example_is_for("larger", PuzzlePartName::A));
+        assert!(!answers.example_is_for("larger", PuzzlePartName::B));
+        assert!(answers.example_is_for("untagged", PuzzlePartName::B));
+    }
+
+    #[test]
+    fn should_check_solutions() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.check(PuzzlePartName::A, &example("1"), &Ok(41.into())),
+            CheckOutcome::Pass
+        );
+        assert_eq!(
+            answers.check(PuzzlePartName::A, &example("1"), &Ok(42.into())),
+            CheckOutcome::Fail {
+                expected: "41".into()
+            }
+        );
+        assert_eq!(
+            answers.check(PuzzlePartName::B, &InputKind::Real, &Ok(1.into())),
+            CheckOutcome::Unknown
+        );
+    }
+
+    #[test]
+    fn should_fail_unsolved_and_failed_parts() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.check(PuzzlePartName::B, &example("1"), &Ok(Answer::Unsolved)),
+            CheckOutcome::Fail {
+                expected: "6".into()
+            }
+        );
+        assert_eq!(
+            answers.check(
+                PuzzlePartName::B,
+                &example("1"),
+                &Err(PuzzleError::new("oops"))
+            ),
+            CheckOutcome::Fail {
+                expected: "6".into()
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_answers_file() {
+        assert!(ExpectedAnswers::parse("[examples.1]\nc = 3\n").is_err());
+        assert!(ExpectedAnswers::parse("[examples.1]\nparts = [\"c\"]\n").is_err());
+        assert!(ExpectedAnswers::parse("[example]\na = 3\n").is_err());
+        assert!(ExpectedAnswers::parse("a = ").is_err());
+    }
+}
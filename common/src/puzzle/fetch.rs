@@ -0,0 +1,315 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Where puzzle inputs are downloaded from, unless told otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Something that can get the text of a puzzle's input.
+pub trait FetchInput {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, FetchError>;
+}
+
+/// Downloads inputs over HTTP, from `<base_url>/<year>/day/<day>/input`,
+/// waiting at least `min_interval` between requests.
+#[derive(Debug)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl HttpFetcher {
+    /// A fetcher for adventofcode.com, logged in with the given session cookie
+    /// (with or without its `session=` prefix).
+    pub fn new(session: impl Into<String>) -> Self {
+        let session = session.into();
+        let session = session.trim();
+        Self {
+            base_url: DEFAULT_BASE_URL.into(),
+            session: session.strip_prefix("session=").unwrap_or(session).into(),
+            min_interval: Duration::from_secs(1),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Fetches from some other server instead, like a local stand-in for tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Sleeps until it's been at least `min_interval` since the last request.
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            thread::sleep(self.min_interval.saturating_sub(last.elapsed()));
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+impl FetchInput for HttpFetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        if self.session.is_empty() {
+            return Err(FetchError::NoSession);
+        }
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.throttle();
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "aoc puzzle runner (input cache)")
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(code, _) => FetchError::Status(url.clone(), code),
+                ureq::Error::Transport(err) => FetchError::Request(url.clone(), err.to_string()),
+            })?;
+
+        response
+            .into_string()
+            .map_err(|err| FetchError::Request(url, err.to_string()))
+    }
+}
+
+/// Checks that downloaded text looks like a puzzle input, rather than an
+/// error page (eg. when the session cookie has expired).
+pub fn validate_input(text: &str) -> Result<(), FetchError> {
+    let invalid = |msg: &str| Err(FetchError::Invalid(msg.into()));
+    let start = text.trim_start();
+
+    if start.is_empty() {
+        invalid("the input is empty")
+    } else if is_html(start) {
+        invalid("got an HTML page instead of an input (is the session cookie valid?)")
+    } else if start.starts_with("Puzzle inputs differ by user") {
+        invalid("not logged in (is the session cookie valid?)")
+    } else if start.starts_with("Please don't repeatedly request this endpoint") {
+        invalid("the puzzle hasn't unlocked yet")
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether text is an HTML page. Only looks for the markers that start a page,
+/// since some inputs are made up of `<` and `>` characters.
+fn is_html(text: &str) -> bool {
+    let head = text.get(..9).unwrap_or(text).to_ascii_lowercase();
+    head.starts_with("<!doctype") || head.starts_with("<html")
+}
+
+/// Keeps downloaded inputs in a directory, as `<dir>/<year>/<day>`, so that
+/// each one is only ever fetched once.
+#[derive(Debug)]
+pub struct InputCache<F> {
+    dir: PathBuf,
+    fetcher: F,
+}
+
+impl<F: FetchInput> InputCache<F> {
+    pub fn new(dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher,
+        }
+    }
+
+    /// Where the input for a day is (or would be) cached.
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("{day:02}"))
+    }
+
+    /// The cached input for a day, if there's a valid one.
+    pub fn cached(&self, year: u32, day: u32) -> Option<String> {
+        let text = fs::read_to_string(self.path(year, day)).ok()?;
+        validate_input(&text).is_ok().then_some(text)
+    }
+
+    /// The input for a day, fetching (and caching) it if it isn't cached yet.
+    /// Nothing is cached unless it passes `validate_input`.
+    pub fn get(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        if let Some(text) = self.cached(year, day) {
+            return Ok(text);
+        }
+
+        let text = self.fetcher.fetch(year, day)?;
+        validate_input(&text)?;
+        write_atomically(&self.path(year, day), &text)?;
+
+        Ok(text)
+    }
+}
+
+/// Writes via a temporary file, so that an interrupted write never leaves a
+/// truncated input behind.
+fn write_atomically(path: &Path, text: &str) -> Result<(), FetchError> {
+    let io_err = |err| FetchError::Io(path.to_path_buf(), err);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_err)?;
+    }
+    let tmp = path.with_extension("part");
+    fs::write(&tmp, text).map_err(io_err)?;
+    fs::rename(&tmp, path).map_err(io_err)
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// There's no session cookie to log in with
+    NoSession,
+
+    /// The request to the given URL couldn't be made
+    Request(String, String),
+
+    /// The server answered the request for the given URL with an error status
+    Status(String, u16),
+
+    /// What came back doesn't look like a puzzle input
+    Invalid(String),
+
+    /// The cache file couldn't be written
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSession => f.write_str(
+                "no session cookie to log in with (set AOC_SESSION, or save it to setup/get-input/.session-cookie)",
+            ),
+            Self::Request(url, err) => write!(f, "could not fetch {url}: {err}"),
+            Self::Status(url, 400) => {
+                write!(
+                    f,
+                    "{url} refused the request (is the session cookie valid?)"
+                )
+            }
+            Self::Status(url, 404) => write!(f, "{url} not found (has the puzzle unlocked yet?)"),
+            Self::Status(url, code) => write!(f, "{url} answered with status {code}"),
+            Self::Invalid(msg) => f.write_str(msg),
+            Self::Io(path, err) => write!(f, "could not write {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        cell::Cell,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        process,
+    };
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Serves a single HTTP request with the given status and body, and returns
+    /// the server's base URL along with a handle to get the request's head.
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let head = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            head
+        });
+
+        (base_url, handle)
+    }
+
+    struct CountingFetcher(Cell<usize>);
+
+    impl FetchInput for CountingFetcher {
+        fn fetch(&self, _year: u32, day: u32) -> Result<String, FetchError> {
+            self.0.set(self.0.get() + 1);
+            Ok(format!("input for day {day}\n"))
+        }
+    }
+
+    #[test]
+    fn should_validate_input() {
+        assert!(validate_input("1 2\n3 4\n").is_ok());
+        assert!(validate_input("\n").is_err());
+        assert!(validate_input("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+        assert!(validate_input("<html>\n<body></body></html>").is_err());
+        assert!(validate_input("<^^>>>vv<v>>v<<\n").is_ok());
+        assert!(validate_input(
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_not_refetch_cached_input() {
+        let dir = temp_dir("refetch");
+        let cache = InputCache::new(&dir, CountingFetcher(Cell::new(0)));
+
+        assert_eq!(cache.get(2024, 6).unwrap(), "input for day 6\n");
+        assert_eq!(cache.get(2024, 6).unwrap(), "input for day 6\n");
+        assert_eq!(cache.fetcher.0.get(), 1);
+        assert!(dir.join("2024/06").is_file());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn should_fetch_from_base_url() {
+        let (base_url, server) = serve_once("200 OK", "7 6 4 2 1\n");
+        let dir = temp_dir("http");
+        let cache = InputCache::new(
+            &dir,
+            HttpFetcher::new("session=abc123\n").with_base_url(base_url),
+        );
+
+        assert_eq!(cache.get(2024, 2).unwrap(), "7 6 4 2 1\n");
+
+        let head = server.join().unwrap().to_lowercase();
+        assert!(head.starts_with("get /2024/day/2/input "));
+        assert!(head.contains("cookie: session=abc123"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn should_not_cache_error_page() {
+        let (base_url, _server) = serve_once("200 OK", "<!DOCTYPE html>\n<html></html>\n");
+        let dir = temp_dir("invalid");
+        let cache = InputCache::new(&dir, HttpFetcher::new("expired").with_base_url(base_url));
+
+        assert!(matches!(cache.get(2024, 3), Err(FetchError::Invalid(_))));
+        assert!(!cache.path(2024, 3).exists());
+    }
+}
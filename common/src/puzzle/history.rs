@@ -0,0 +1,169 @@
+use super::{Answer, PuzzlePartName};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::SystemTime,
+};
+
+/// The file in a puzzle's `data` directory that holds its answer history.
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+/// What adventofcode.com said about a submitted answer.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mark {
+    Accepted,
+    Rejected,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Mark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mark::Accepted => f.write_str("accepted"),
+            Mark::Rejected => f.write_str("rejected"),
+            Mark::TooHigh => f.write_str("too high"),
+            Mark::TooLow => f.write_str("too low"),
+        }
+    }
+}
+
+/// One line of a puzzle's history: either an answer that was computed for its
+/// main input (with `duration_ns` and `commit`), or a `mark` given to an answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub part: PuzzlePartName,
+    pub answer: Answer,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ns: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mark: Option<Mark>,
+}
+
+impl HistoryEntry {
+    /// An entry for `answer`, timestamped now, with nothing else filled in.
+    pub fn new(part: PuzzlePartName, answer: Answer) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |t| t.as_secs()),
+            part,
+            answer,
+            duration_ns: None,
+            commit: None,
+            mark: None,
+        }
+    }
+}
+
+/// Everything in a puzzle's `data/history.jsonl`, oldest first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Reads the history in `data_dir`, which is empty if there isn't a history file.
+    pub fn load(data_dir: &Path) -> Result<Self, String> {
+        let path = data_dir.join(HISTORY_FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(format!("could not read {}: {err}", path.display())),
+        };
+
+        Self::parse(&text).map_err(|msg| format!("{}: {msg}", path.display()))
+    }
+
+    pub(super) fn parse(text: &str) -> Result<Self, String> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line).map_err(|err| format!("line {}: {err}", idx + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Adds an entry to the end of the history file in `data_dir`.
+    pub fn append(data_dir: &Path, entry: &HistoryEntry) -> Result<(), String> {
+        let path = data_dir.join(HISTORY_FILE);
+        let line = serde_json::to_string(entry).unwrap();
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| writeln!(file, "{line}"))
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter()
+    }
+
+    /// The most recently computed answer to a part, if there is one.
+    pub fn latest_answer(&self, part: PuzzlePartName) -> Option<&Answer> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.part == part && entry.mark.is_none())
+            .map(|entry| &entry.answer)
+    }
+
+    /// Each marked answer to a part, with the last mark it was given.
+    pub fn marks(&self, part: PuzzlePartName) -> Vec<(&Answer, Mark)> {
+        let mut marks: Vec<(&Answer, Mark)> = vec![];
+        for entry in self.entries.iter().filter(|entry| entry.part == part) {
+            if let Some(mark) = entry.mark {
+                marks.retain(|(answer, _)| *answer != &entry.answer);
+                marks.push((&entry.answer, mark));
+            }
+        }
+
+        marks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(lines: &[&str]) -> History {
+        History::parse(&lines.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn should_read_answers_and_marks() {
+        let history = history(&[
+            r#"{"timestamp":1,"part":"A","answer":41,"duration_ns":12,"commit":"abc1234"}"#,
+            r#"{"timestamp":2,"part":"A","answer":41,"mark":"too-high"}"#,
+            r#"{"timestamp":3,"part":"A","answer":"6,1"}"#,
+            r#"{"timestamp":4,"part":"A","answer":41,"mark":"rejected"}"#,
+        ]);
+
+        assert_eq!(history.entries().count(), 4);
+        assert_eq!(
+            history.latest_answer(PuzzlePartName::A),
+            Some(&Answer::from("6,1"))
+        );
+        assert_eq!(history.latest_answer(PuzzlePartName::B), None);
+        assert_eq!(
+            history.marks(PuzzlePartName::A),
+            vec![(&Answer::Integer(41), Mark::Rejected)]
+        );
+        assert!(History::parse("{\"timestamp\":1}").is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// The name of the directory (in a puzzle's `data` directory) holding example inputs.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Which of a puzzle's inputs is being solved.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    /// The real puzzle input, `data/input`
+    Real,
+
+    /// One of the named example inputs in `data/examples`
+    Example(String),
+
+    /// Some other file given with `--input` (where `-` means stdin)
+    Custom(PathBuf),
+}
+
+impl InputKind {
+    /// A short description of this kind of input, for machine-readable output.
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputKind::Real => "input",
+            InputKind::Example(_) => "example",
+            InputKind::Custom(_) => "custom",
+        }
+    }
+
+    pub fn example_name(&self) -> Option<&str> {
+        match self {
+            InputKind::Example(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// The names of all of the example inputs in `data_dir`, in order.
+pub fn example_names(data_dir: &Path) -> Result<Vec<String>, InputError> {
+    let dir = data_dir.join(EXAMPLES_DIR);
+    let entries = fs::read_dir(&dir).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NoExamples(dir.clone()),
+        _ => InputError::Unreadable(dir.clone(), err),
+    })?;
+
+    let mut names = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| !name.starts_with('.'))
+        .collect::<Vec<_>>();
+    names.sort_by(|a, b| natural_order(a, b));
+
+    Ok(names)
+}
+
+/// Orders names so that numbered examples come in numeric order ("2" before "10").
+fn natural_order(a: &str, b: &str) -> std::cmp::Ordering {
+    match (a.parse::<u32>(), b.parse::<u32>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+        (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+/// Where a puzzle's input text comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file on disk
+    File(PathBuf),
+
+    /// Whatever is piped into standard input
+    Stdin,
+}
+
+impl InputSource {
+    /// Where to find an input for a puzzle whose data lives in `data_dir`.
+    pub fn of(data_dir: &Path, kind: &InputKind) -> Self {
+        match kind {
+            InputKind::Real => Self::File(data_dir.join("input")),
+            InputKind::Example(name) => Self::File(data_dir.join(EXAMPLES_DIR).join(name)),
+            InputKind::Custom(path) if path == Path::new("-") => Self::Stdin,
+            InputKind::Custom(path) => Self::File(path.clone()),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Self::File(path) => fs::read_to_string(path).map_err(|err| match err.kind() {
+                io::ErrorKind::NotFound => InputError::Missing(path.clone()),
+                _ => InputError::Unreadable(path.clone(), err),
+            }),
+            Self::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(InputError::Stdin)?;
+                Ok(text)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file doesn't exist (probably hasn't been downloaded yet)
+    Missing(PathBuf),
+
+    /// The input file exists but couldn't be read
+    Unreadable(PathBuf, io::Error),
+
+    /// Reading from stdin failed
+    Stdin(io::Error),
+
+    /// There's no directory of example inputs
+    NoExamples(PathBuf),
+
+    /// There's no example input with the given name (in the given directory)
+    NoSuchExample(String, PathBuf),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "no input file found at {} (download it with `just get-input <day>`, or pass `--input <path>`)",
+                path.display()
+            ),
+            InputError::Unreadable(path, err) => {
+                write!(f, "could not read input file {}: {err}", path.display())
+            }
+            InputError::Stdin(err) => write!(f, "could not read input from stdin: {err}"),
+            InputError::NoExamples(dir) => write!(
+                f,
+                "no examples found at {} (download one with `just get-example <day>`)",
+                dir.display()
+            ),
+            InputError::NoSuchExample(name, dir) => {
+                write!(f, "no example called '{name}' in {}", dir.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_resolve_input_sources() {
+        let data = Path::new("data");
+
+        assert_eq!(
+            InputSource::of(data, &InputKind::Real),
+            InputSource::File("data/input".into())
+        );
+        assert_eq!(
+            InputSource::of(data, &InputKind::Example("2".into())),
+            InputSource::File("data/examples/2".into())
+        );
+        assert_eq!(
+            InputSource::of(data, &InputKind::Custom("other.txt".into())),
+            InputSource::File("other.txt".into())
+        );
+        assert_eq!(
+            InputSource::of(data, &InputKind::Custom("-".into())),
+            InputSource::Stdin
+        );
+    }
+
+    #[test]
+    fn should_sort_example_names() {
+        let mut names = vec!["larger", "10", "2", "1", "edge"];
+        names.sort_by(|a, b| natural_order(a, b));
+
+        assert_eq!(names, ["1", "2", "10", "edge", "larger"]);
+    }
+
+    #[test]
+    fn should_report_missing_input() {
+        let source = InputSource::File("definitely/not/here".into());
+        assert!(matches!(source.read(), Err(InputError::Missing(_))));
+    }
+}
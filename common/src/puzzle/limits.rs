@@ -0,0 +1,334 @@
+use super::{catch_panics, Answer, ParamSettings, PuzzleError};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
+
+/// Solvers given a timeout run on a thread with this much stack, which is more
+/// than the main thread gets, so that recursive solvers don't need to worry.
+const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// A solver, as it's stored in a `PartEntry`.
+pub type Solver = fn(&str, &ParamSettings) -> Result<Answer, PuzzleError>;
+
+/// Runs a solver (catching any panic), giving up on it after `timeout` if there
+/// is one, and measuring its peak memory if `track` is set.
+///
+/// A solver that times out can't be stopped, so it's left to finish (or not) in
+/// the background until the process exits. While it does, it keeps a CPU busy,
+/// which can slow down (and so skew the timings of) whatever gets solved next.
+/// Its allocations aren't counted towards anyone else's peak memory, though,
+/// since memory is tracked per thread.
+pub fn solve_with_timeout(
+    solve: Solver,
+    input: &str,
+    params: &ParamSettings,
+    timeout: Option<Duration>,
+    track: bool,
+) -> (Result<Answer, PuzzleError>, Option<Bytes>) {
+    let Some(timeout) = timeout else {
+        return solve_tracked(solve, input, params, track);
+    };
+
+    let (input, params) = (input.to_string(), params.clone());
+    let solved = run_on_worker(1, timeout, move || {
+        Ok(solve_tracked(solve, &input, &params, track))
+    });
+
+    match solved {
+        Ok(mut runs) => runs.pop().expect("the worker sends one result per run"),
+        Err(err) => (Err(err), None),
+    }
+}
+
+fn solve_tracked(
+    solve: Solver,
+    input: &str,
+    params: &ParamSettings,
+    track: bool,
+) -> (Result<Answer, PuzzleError>, Option<Bytes>) {
+    match track {
+        true => {
+            let (solution, peak) = track_memory(|| catch_panics(|| solve(input, params)));
+            (solution, Some(peak))
+        }
+        false => (catch_panics(|| solve(input, params)), None),
+    }
+}
+
+/// Calls `run` up to `runs` times on a worker thread, collecting the results,
+/// and stopping at the first error. Gives up (leaving the worker behind) if any
+/// single run takes longer than `timeout`.
+pub(super) fn run_on_worker<T, F>(
+    runs: usize,
+    timeout: Duration,
+    mut run: F,
+) -> Result<Vec<T>, PuzzleError>
+where
+    T: Send + 'static,
+    F: FnMut() -> Result<T, PuzzleError> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name("solver".into())
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            for _ in 0..runs {
+                let result = run();
+                let failed = result.is_err();
+                // the receiver is gone if a run took too long, so stop bothering
+                if sender.send(result).is_err() || failed {
+                    break;
+                }
+            }
+        });
+    if let Err(err) = spawned {
+        return Err(PuzzleError::new(format!(
+            "could not start the solver's thread ({err})"
+        )));
+    }
+
+    (0..runs)
+        .map(|_| match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                Err(PuzzleError::new(format!("timed out after {timeout:?}")))
+            }
+            // the worker died without sending anything, so `run` must have panicked
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(PuzzleError::new("the solver's thread stopped unexpectedly"))
+            }
+        })
+        .collect()
+}
+
+/// Parses a timeout given in (possibly fractional) seconds.
+pub fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    secs.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("expected a positive number of seconds, not `{secs}`"))
+}
+
+/// Wraps the system allocator to keep track of how much memory each thread has
+/// in use, but only while `track_memory` has switched tracking on for that thread,
+/// so that it costs next to nothing otherwise.
+///
+/// A library shouldn't choose the allocator for everything that uses it, so each
+/// binary that wants `--memory` to work installs this itself:
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: common::puzzle::TrackingAllocator = common::puzzle::TrackingAllocator;
+/// ```
+pub struct TrackingAllocator;
+
+/// Set by the first allocation that goes through a `TrackingAllocator`, which
+/// tells us whether one has been installed.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// The memory this thread has in use, and the most it has had in use at once,
+    /// while `track_memory` is running on it.
+    static USAGE: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+}
+
+impl TrackingAllocator {
+    fn grew(&self, bytes: usize) {
+        if !INSTALLED.load(Ordering::Relaxed) {
+            INSTALLED.store(true, Ordering::Relaxed);
+        }
+
+        // the thread-local may already be gone while the thread is shutting down
+        let _ = USAGE.try_with(|usage| {
+            if let Some((in_use, peak)) = usage.get() {
+                let in_use = in_use + bytes;
+                usage.set(Some((in_use, peak.max(in_use))));
+            }
+        });
+    }
+
+    fn shrank(&self, bytes: usize) {
+        let _ = USAGE.try_with(|usage| {
+            // memory allocated before tracking started (or on another thread)
+            // might be freed during it
+            if let Some((in_use, peak)) = usage.get() {
+                usage.set(Some((in_use.saturating_sub(bytes), peak)));
+            }
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.shrank(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.shrank(layout.size());
+            self.grew(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// An amount of memory, shown in the most sensible unit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        match unit {
+            0 => write!(f, "{} B", self.0),
+            _ => write!(f, "{size:.1} {}", UNITS[unit]),
+        }
+    }
+}
+
+/// Whether a `TrackingAllocator` is the global allocator, without which
+/// `track_memory` can't see anything.
+pub fn can_track_memory() -> bool {
+    // make sure something has been allocated, in case nothing has yet
+    drop(std::hint::black_box(Box::new(0u8)));
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f`, returning what it returned along with the most memory that this
+/// thread had allocated at once while it ran (on top of what was already in use).
+/// Memory allocated by any threads that `f` starts isn't counted.
+pub fn track_memory<T>(f: impl FnOnce() -> T) -> (T, Bytes) {
+    let outer = USAGE.replace(Some((0, 0)));
+    let result = f();
+    let (_, peak) = USAGE.replace(outer).unwrap_or_default();
+
+    (result, Bytes(peak))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+    fn slow(input: &str, _: &ParamSettings) -> Result<Answer, PuzzleError> {
+        thread::sleep(Duration::from_millis(input.parse().unwrap()));
+        Ok(Answer::Integer(41))
+    }
+
+    #[test]
+    fn should_time_out() {
+        let params = ParamSettings::default();
+        let timeout = Some(Duration::from_millis(50));
+
+        assert_eq!(
+            solve_with_timeout(slow, "0", &params, timeout, false),
+            (Ok(Answer::Integer(41)), None)
+        );
+        assert_eq!(
+            solve_with_timeout(slow, "500", &params, timeout, false)
+                .0
+                .unwrap_err()
+                .message(),
+            "timed out after 50ms"
+        );
+    }
+
+    #[test]
+    fn should_parse_timeouts() {
+        assert_eq!(parse_timeout("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn should_show_sizes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.0 MiB");
+    }
+
+    #[test]
+    fn should_track_peak_memory() {
+        let (len, peak) = track_memory(|| {
+            let big = vec![0u8; 1 << 20];
+            drop(big);
+            vec![0u8; 1024].len()
+        });
+
+        assert_eq!(len, 1024);
+        assert!(peak >= Bytes(1 << 20));
+        assert!(can_track_memory());
+    }
+
+    #[test]
+    fn should_track_memory_per_thread() {
+        let ((), peak) = track_memory(|| {
+            thread::spawn(|| vec![0u8; 1 << 20].len()).join().unwrap();
+        });
+
+        assert!(peak < Bytes(1 << 20));
+    }
+
+    #[test]
+    fn should_stop_worker_at_first_error() {
+        let mut count = 0;
+        let result = run_on_worker(5, Duration::from_secs(5), move || {
+            count += 1;
+            match count {
+                1 => Ok(count),
+                _ => Err(PuzzleError::new("stop")),
+            }
+        });
+
+        assert_eq!(result.unwrap_err().message(), "stop");
+    }
+
+    #[test]
+    fn should_not_mistake_dead_worker_for_timeout() {
+        let result = run_on_worker(1, Duration::from_secs(5), || -> Result<(), _> {
+            panic!("the worker died")
+        });
+
+        assert_eq!(
+            result.unwrap_err().message(),
+            "the solver's thread stopped unexpectedly"
+        );
+    }
+}
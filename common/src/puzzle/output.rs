@@ -0,0 +1,151 @@
+use super::{Answer, CheckOutcome, PuzzlePartName, Verdict};
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How the runner reports its results.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colorful text for humans
+    #[default]
+    Pretty,
+
+    /// The same text as `pretty`, without any colors
+    Plain,
+
+    /// One JSON object per line, for scripts
+    Json,
+}
+
+impl OutputFormat {
+    /// Whether to print text for humans (rather than machine-readable records).
+    pub fn is_human(&self) -> bool {
+        *self != OutputFormat::Json
+    }
+
+    /// Sets up terminal colors to suit this format. Should be called once,
+    /// before anything is printed.
+    pub fn configure(&self) {
+        if *self != OutputFormat::Pretty {
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Prints a record as a line of JSON, if that's what this format calls for.
+    pub fn emit<T: Serialize>(&self, record: &T) {
+        if *self == OutputFormat::Json {
+            println!("{}", serde_json::to_string(record).unwrap());
+        }
+    }
+}
+
+/// The machine-readable result of solving one part of a puzzle.
+#[derive(Clone, Debug, Serialize)]
+pub struct PartRecord<'a> {
+    pub year: u32,
+    pub day: &'a str,
+    pub part: PuzzlePartName,
+    pub description: &'a str,
+    pub input_kind: &'a str,
+
+    /// The name of the example input, if one was solved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<&'a str>,
+
+    pub answer: &'a Answer,
+    pub duration_ns: u128,
+
+    /// The most memory allocated at once while solving, if it was tracked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_memory_bytes: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<&'a CheckOutcome>,
+
+    /// Whether the answer to the main input could be right, going by its history
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<&'a Verdict>,
+}
+
+/// A machine-readable note that a puzzle couldn't be run at all.
+#[derive(Clone, Debug, Serialize)]
+pub struct ErrorRecord<'a> {
+    pub year: u32,
+    pub day: &'a str,
+    pub error: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serialize_part_record() {
+        let record = PartRecord {
+            year: 2024,
+            day: "06",
+            part: PuzzlePartName::A,
+            description: "Count things",
+            input_kind: "example",
+            example: Some("1"),
+            answer: &Answer::Integer(41),
+            duration_ns: 1234,
+            peak_memory_bytes: None,
+            error: None,
+            check: Some(&CheckOutcome::Pass),
+            verdict: None,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"year":2024,"day":"06","part":"A","description":"Count things","input_kind":"example","example":"1","answer":41,"duration_ns":1234,"check":{"status":"pass"}}"#
+        );
+    }
+
+    #[test]
+    fn should_serialize_verdict() {
+        let record = PartRecord {
+            year: 2024,
+            day: "06",
+            part: PuzzlePartName::A,
+            description: "",
+            input_kind: "input",
+            example: None,
+            answer: &Answer::Integer(80),
+            duration_ns: 0,
+            peak_memory_bytes: None,
+            error: None,
+            check: None,
+            verdict: Some(&Verdict::TooHigh { bound: 70 }),
+        };
+
+        assert!(serde_json::to_string(&record)
+            .unwrap()
+            .ends_with(r#""verdict":{"status":"too-high","bound":70}}"#));
+    }
+
+    #[test]
+    fn should_skip_missing_fields() {
+        let record = PartRecord {
+            year: 2024,
+            day: "06",
+            part: PuzzlePartName::B,
+            description: "",
+            input_kind: "input",
+            example: None,
+            answer: &Answer::from("6,1"),
+            duration_ns: 0,
+            peak_memory_bytes: None,
+            error: None,
+            check: None,
+            verdict: None,
+        };
+
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(!json.contains("check"));
+        assert!(!json.contains("verdict"));
+        assert!(!json.contains("example"));
+    }
+}
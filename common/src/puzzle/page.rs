@@ -0,0 +1,244 @@
+use super::{expected::ANSWERS_FILE, input::EXAMPLES_DIR};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The parts of a (saved) puzzle description page that are useful for setting
+/// up examples: its code blocks, and the emphasized bits of code, which are
+/// usually the answers to the examples.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzlePage {
+    /// The text of every `<pre><code>` block, in order
+    pub blocks: Vec<String>,
+
+    /// The text of every `<code><em>` span, in order
+    pub answers: Vec<String>,
+}
+
+impl PuzzlePage {
+    pub fn parse(html: &str) -> Self {
+        Self {
+            blocks: between(html, "<pre><code>", "</code></pre>")
+                .map(|block| decode_entities(&strip_tags(block)))
+                .collect(),
+            answers: between(html, "<code><em>", "</em></code>")
+                .map(|answer| decode_entities(&strip_tags(answer)))
+                .collect(),
+        }
+    }
+
+    /// Saves the code blocks at `indices` as examples in `data_dir`, numbered from
+    /// 1 but skipping any numbers already taken by examples that aren't empty.
+    /// Nothing is written unless every index is for one of the page's blocks.
+    ///
+    /// Returns the names of the examples that were written.
+    pub fn save_examples(&self, data_dir: &Path, indices: &[usize]) -> Result<Vec<String>, String> {
+        let blocks = indices
+            .iter()
+            .map(|&idx| {
+                self.blocks.get(idx).ok_or_else(|| {
+                    format!(
+                        "there's no code block {idx} (there are {})",
+                        self.blocks.len()
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let dir = data_dir.join(EXAMPLES_DIR);
+        fs::create_dir_all(&dir)
+            .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+
+        let is_free = |path: &PathBuf| fs::metadata(path).map_or(true, |meta| meta.len() == 0);
+        let mut names = (1..)
+            .map(|n| n.to_string())
+            .filter(|name| is_free(&dir.join(name)));
+
+        let mut written = vec![];
+        for block in blocks {
+            let name = names.next().unwrap();
+            let path = dir.join(&name);
+            fs::write(&path, block)
+                .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+            written.push(name);
+        }
+
+        Ok(written)
+    }
+
+    /// Adds the page's emphasized answers to the end of `data/answers.toml`, as
+    /// comments under a note saying which examples they might belong to, ready to
+    /// be uncommented into the right `[examples.<name>]` table.
+    ///
+    /// Returns the path of the answers file, or `None` if there were no answers.
+    pub fn save_candidates(
+        &self,
+        data_dir: &Path,
+        examples: &[String],
+    ) -> Result<Option<PathBuf>, String> {
+        if self.answers.is_empty() {
+            return Ok(None);
+        }
+
+        let path = data_dir.join(ANSWERS_FILE);
+        let mut contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("could not read {}: {err}", path.display())),
+        };
+        if !contents.is_empty() && !contents.ends_with("\n\n") {
+            contents += if contents.ends_with('\n') {
+                "\n"
+            } else {
+                "\n\n"
+            };
+        }
+
+        contents += &format!(
+            "# Candidate answers from the puzzle page, for example(s) {}:\n",
+            examples.join(", ")
+        );
+        // an answer can span lines (like a grid), each of which needs commenting out
+        for line in self.answers.iter().flat_map(|answer| answer.lines()) {
+            contents += &format!("# {line}\n");
+        }
+
+        fs::write(&path, contents)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        Ok(Some(path))
+    }
+}
+
+/// Every piece of `text` that's between an `open` and the next `close`.
+fn between<'a>(text: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)? + open.len();
+        let len = rest[start..].find(close)?;
+        let found = &rest[start..start + len];
+        rest = &rest[start + len + close.len()..];
+        Some(found)
+    })
+}
+
+/// Removes any tags (like the `<em>` highlights within code blocks).
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<article class="day-desc"><h2>--- Day 3: Example ---</h2>
+<p>For example:</p>
+<pre><code>x<em>mul(2,4)</em>&amp;mul[3,7]!^<em>don't()</em>_mul(5,5)
+</code></pre>
+<p>Adding up the results produces <code><em>161</em></code>.</p>
+<pre><code>&lt;1,2&gt;
+</code></pre>
+<p>This time, the sum is <code><em>48</em></code> (not <code>50</code>).</p>
+</article>"#;
+
+    #[test]
+    fn should_parse_blocks_and_answers() {
+        let page = PuzzlePage::parse(PAGE);
+
+        assert_eq!(
+            page.blocks,
+            vec!["xmul(2,4)&mul[3,7]!^don't()_mul(5,5)\n", "<1,2>\n"]
+        );
+        assert_eq!(page.answers, vec!["161", "48"]);
+    }
+
+    #[test]
+    fn should_save_examples_around_existing_ones() {
+        let data_dir = std::env::temp_dir().join(format!("aoc-page-{}", std::process::id()));
+        let examples = data_dir.join(EXAMPLES_DIR);
+        fs::create_dir_all(&examples).unwrap();
+        fs::write(examples.join("1"), "").unwrap();
+        fs::write(examples.join("2"), "taken\n").unwrap();
+
+        let page = PuzzlePage::parse(PAGE);
+        assert_eq!(page.save_examples(&data_dir, &[1, 0]).unwrap(), ["1", "3"]);
+        assert_eq!(fs::read_to_string(examples.join("1")).unwrap(), "<1,2>\n");
+        assert_eq!(fs::read_to_string(examples.join("2")).unwrap(), "taken\n");
+        assert!(page.save_examples(&data_dir, &[2]).is_err());
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+
+    #[test]
+    fn should_not_save_any_examples_if_one_is_missing() {
+        let data_dir = std::env::temp_dir().join(format!("aoc-page-bad-{}", std::process::id()));
+
+        let page = PuzzlePage::parse(PAGE);
+        assert!(page.save_examples(&data_dir, &[0, 5]).is_err());
+        assert!(!data_dir.join(EXAMPLES_DIR).join("1").exists());
+
+        let _ = fs::remove_dir_all(data_dir);
+    }
+
+    #[test]
+    fn should_save_candidate_answers() {
+        let data_dir =
+            std::env::temp_dir().join(format!("aoc-page-answers-{}", std::process::id()));
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::write(data_dir.join(ANSWERS_FILE), "[examples.1]\n# a = 0\n").unwrap();
+
+        let page = PuzzlePage::parse(PAGE);
+        let path = page
+            .save_candidates(&data_dir, &["1".into(), "2".into()])
+            .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(path.unwrap()).unwrap(),
+            "[examples.1]\n# a = 0\n\n# Candidate answers from the puzzle page, for example(s) 1, 2:\n# 161\n# 48\n"
+        );
+        assert_eq!(
+            PuzzlePage::default().save_candidates(&data_dir, &[]),
+            Ok(None)
+        );
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+
+    #[test]
+    fn should_comment_out_every_line_of_candidate_answers() {
+        let data_dir = std::env::temp_dir().join(format!("aoc-page-grid-{}", std::process::id()));
+        fs::create_dir_all(&data_dir).unwrap();
+
+        let page =
+            PuzzlePage::parse("<p>The grid ends up as <code><em>#.#\n.#.\n</em></code>.</p>");
+        let path = page.save_candidates(&data_dir, &["1".into()]).unwrap();
+
+        let contents = fs::read_to_string(path.unwrap()).unwrap();
+        assert_eq!(
+            contents,
+            "# Candidate answers from the puzzle page, for example(s) 1:\n# #.#\n# .#.\n"
+        );
+        assert!(contents.parse::<toml::Table>().is_ok());
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+}
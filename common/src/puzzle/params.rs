@@ -0,0 +1,189 @@
+use super::PuzzleError;
+
+/// Values that a solver needs but which aren't part of its input, like the
+/// size of a grid. These usually differ between the example and the real input.
+pub trait PuzzleParams: Sized {
+    /// The values to use with the example input.
+    fn example() -> Self;
+
+    /// The values to use with the real input.
+    fn input() -> Self;
+
+    /// The names of the parameters, as given to `--param <name>=<value>`.
+    fn names() -> &'static [&'static str];
+
+    /// Sets the parameter called `name`, parsing its value from text.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+/// Most puzzles don't need any parameters.
+impl PuzzleParams for () {
+    fn example() -> Self {}
+
+    fn input() -> Self {}
+
+    fn names() -> &'static [&'static str] {
+        &[]
+    }
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter '{name}'"))
+    }
+}
+
+/// Defines a struct of puzzle parameters, along with its `PuzzleParams` impl.
+/// Each field gets a default for the example input and one for the real input,
+/// and must implement `FromStr` so that it can be set with `--param`:
+/// ```
+/// common::puzzle_params! {
+///     pub struct RaceParams {
+///         /// Only count cheats that save at least this many steps
+///         threshold: usize = (example: 20, input: 100),
+///     }
+/// }
+///
+/// use common::puzzle::PuzzleParams;
+/// assert_eq!(RaceParams::example().threshold, 20);
+/// ```
+#[macro_export]
+macro_rules! puzzle_params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                $field:ident: $ty:ty = (example: $example:expr, input: $input:expr)
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq)]
+        $vis struct $name {
+            $(
+                $(#[doc = $doc])*
+                pub $field: $ty,
+            )*
+        }
+
+        impl $crate::puzzle::PuzzleParams for $name {
+            fn example() -> Self {
+                Self { $($field: $example,)* }
+            }
+
+            fn input() -> Self {
+                Self { $($field: $input,)* }
+            }
+
+            fn names() -> &'static [&'static str] {
+                &[$(stringify!($field)),*]
+            }
+
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                match name {
+                    $(
+                        stringify!($field) => {
+                            self.$field = value
+                                .parse()
+                                .map_err(|err| format!("invalid value '{value}' for {name} ({err})"))?;
+                        }
+                    )*
+                    _ => return Err(format!("unknown parameter '{name}'")),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Which parameters to solve with: the defaults for the example or the real
+/// input, with any overrides from the command line applied on top.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParamSettings {
+    pub example: bool,
+    pub overrides: Vec<(String, String)>,
+}
+
+impl ParamSettings {
+    /// The default parameters for the example input.
+    pub fn example() -> Self {
+        Self {
+            example: true,
+            overrides: vec![],
+        }
+    }
+
+    /// Builds a part's parameters. Overrides for parameters it doesn't have are
+    /// ignored, since they may be meant for the puzzle's other part.
+    pub fn resolve<P: PuzzleParams>(&self) -> Result<P, PuzzleError> {
+        let mut params = if self.example {
+            P::example()
+        } else {
+            P::input()
+        };
+
+        for (name, value) in &self.overrides {
+            if P::names().contains(&name.as_str()) {
+                params.set(name, value)?;
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+/// Parses a `<name>=<value>` parameter override from the command line.
+pub fn parse_override(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected <name>=<value>, got '{s}'"))?;
+
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::puzzle_params! {
+        struct TestParams {
+            size: usize = (example: 7, input: 71),
+            label: String = (example: "small".into(), input: "big".into()),
+        }
+    }
+
+    #[test]
+    fn should_pick_defaults_for_input_kind() {
+        let example = ParamSettings::example().resolve::<TestParams>().unwrap();
+        let input = ParamSettings::default().resolve::<TestParams>().unwrap();
+
+        assert_eq!(example.size, 7);
+        assert_eq!(input.size, 71);
+        assert_eq!(input.label, "big");
+    }
+
+    #[test]
+    fn should_apply_overrides() {
+        let settings = ParamSettings {
+            example: true,
+            overrides: vec![
+                parse_override("size=12").unwrap(),
+                ("other".into(), "1".into()),
+            ],
+        };
+
+        let params = settings.resolve::<TestParams>().unwrap();
+        assert_eq!((params.size, params.label.as_str()), (12, "small"));
+    }
+
+    #[test]
+    fn should_reject_bad_overrides() {
+        assert!(parse_override("size").is_err());
+
+        let settings = ParamSettings {
+            example: false,
+            overrides: vec![("size".into(), "big".into())],
+        };
+        let err = settings.resolve::<TestParams>().unwrap_err();
+        assert!(err.message().starts_with("invalid value 'big' for size"));
+    }
+}
@@ -0,0 +1,511 @@
+use super::{
+    bench::{self, BenchArgs, BenchRecord},
+    read_inputs, Answer, CheckOutcome, ErrorRecord, ExpectedAnswers, FetchInput, InputCache,
+    InputKind, PartResult, PuzzleArgs, PuzzleEntry, PuzzlePartName,
+};
+use colored::Colorize;
+use std::{fs, ops::RangeInclusive, path::PathBuf, str::FromStr};
+
+/// Which days to run, as given on the command line. Accepts a single
+/// day (`6`), an exclusive range (`1..8`), or an inclusive range (`1..=25`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaySelection(RangeInclusive<u32>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        Self(1..=25)
+    }
+
+    pub fn contains(&self, day: u32) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |d: &str| {
+            d.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("'{d}' is not a valid day number"))
+        };
+
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = parse_day(end)?;
+            if end == 0 {
+                return Err(format!("'{s}' does not contain any days"));
+            }
+            parse_day(start)?..=end - 1
+        } else {
+            let day = parse_day(s)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(format!("'{s}' does not contain any days"));
+        }
+
+        Ok(Self(range))
+    }
+}
+
+/// A collection of every puzzle that can be run, along with the directory
+/// containing their crates (as `<year>/<day>`, each with a `data` directory).
+pub struct Registry {
+    root: PathBuf,
+    entries: Vec<PuzzleEntry>,
+}
+
+/// The results of running one day's puzzle, for the summary table.
+struct DayResult {
+    name: &'static str,
+    outcome: Result<Vec<PartResult>, String>,
+}
+
+impl Registry {
+    pub fn new(root: impl Into<PathBuf>, mut entries: Vec<PuzzleEntry>) -> Self {
+        entries.sort_by_key(|entry| (entry.year, entry.name));
+        Self {
+            root: root.into(),
+            entries,
+        }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &PuzzleEntry> {
+        self.entries.iter()
+    }
+
+    /// The `data` directory belonging to a puzzle.
+    pub fn data_dir(&self, entry: &PuzzleEntry) -> PathBuf {
+        self.root
+            .join(entry.year.to_string())
+            .join(entry.name)
+            .join("data")
+    }
+
+    /// Runs every registered puzzle whose day is in `days`. If more than one
+    /// day is run, finishes with a table summarizing all of the solutions.
+    ///
+    /// Returns `false` if any day couldn't be run or any checked solution was wrong.
+    pub fn run(&self, days: &DaySelection, args: &PuzzleArgs) -> bool {
+        args.format.configure();
+        let Some(selected) = self.select(days) else {
+            return false;
+        };
+        if let Err(msg) = args.validate(selected.len()) {
+            eprintln!("{} {msg}", "Error:".red().bold());
+            return false;
+        }
+
+        let mut results = vec![];
+        for (idx, entry) in selected.iter().enumerate() {
+            if idx > 0 && args.format.is_human() {
+                println!("{}", "===".dimmed());
+            }
+
+            let outcome = self.run_entry(entry, args);
+            if let Err(msg) = &outcome {
+                report_error(entry, msg, args);
+            }
+
+            results.push(DayResult {
+                name: entry.name,
+                outcome,
+            });
+        }
+
+        if results.len() > 1 && args.format.is_human() {
+            println!();
+            print_summary(&results);
+        }
+
+        results.iter().all(|result| {
+            result
+                .outcome
+                .as_ref()
+                .is_ok_and(|parts| !parts.iter().any(PartResult::failed))
+        })
+    }
+
+    /// Repeatedly solves every registered puzzle whose day is in `days`, printing
+    /// timing statistics for each part and optionally writing them to a file.
+    ///
+    /// Returns `false` if any day couldn't be run or any checked solution was wrong.
+    pub fn bench(&self, days: &DaySelection, args: &PuzzleArgs, bench_args: &BenchArgs) -> bool {
+        args.format.configure();
+        let Some(selected) = self.select(days) else {
+            return false;
+        };
+        if let Err(msg) = args.validate(selected.len()) {
+            eprintln!("{} {msg}", "Error:".red().bold());
+            return false;
+        }
+
+        if bench::build_profile() == "debug" {
+            eprintln!(
+                "{}",
+                "Warning: benchmarking a debug build; pass --release for meaningful timings"
+                    .yellow()
+            );
+        }
+
+        let commit = bench::current_commit();
+
+        let mut ok = true;
+        let mut records = vec![];
+        for entry in selected {
+            let data_dir = self.data_dir(entry);
+            let loaded = entry
+                .check_params(args.part, &args.params)
+                .and_then(|()| ExpectedAnswers::load(&data_dir))
+                .and_then(|answers| {
+                    let jobs = args
+                        .inputs(&data_dir, &answers)
+                        .map_err(|e| e.to_string())?;
+                    let inputs = read_inputs(&data_dir, &jobs).map_err(|e| e.to_string())?;
+                    Ok((answers, jobs, inputs))
+                });
+            let (answers, jobs, inputs) = match loaded {
+                Ok(loaded) => loaded,
+                Err(msg) => {
+                    report_error(entry, &msg, args);
+                    ok = false;
+                    continue;
+                }
+            };
+
+            for (part, kind) in jobs {
+                let Some(kind) = kind else {
+                    continue;
+                };
+                let input = &inputs[&kind];
+                let params = args.param_settings(&kind);
+
+                let (solution, stats) = match bench::bench_part(
+                    entry.part(part),
+                    input,
+                    &params,
+                    bench_args,
+                    args.timeout,
+                ) {
+                    Ok(bench) => bench,
+                    Err(err) => {
+                        let msg = format!("part {part} failed: {err}");
+                        report_error(entry, &msg, args);
+                        ok = false;
+                        continue;
+                    }
+                };
+                let check = args
+                    .is_checking()
+                    .then(|| answers.check(part, &kind, &Ok(solution)));
+
+                let mark = match check {
+                    Some(CheckOutcome::Pass) => "✔".green(),
+                    Some(CheckOutcome::Fail { .. }) => {
+                        ok = false;
+                        "✘".red()
+                    }
+                    Some(CheckOutcome::Unknown) => "?".yellow(),
+                    None => " ".normal(),
+                };
+
+                if args.format.is_human() {
+                    println!(
+                        "{} {mark} {} {} {} {}",
+                        format!("Puzzle {} Part {part}{}", entry.name, example_note(&kind)).blue(),
+                        format!("min {:>12}", format!("{:?}", stats.min)).green(),
+                        format!("median {:>12}", format!("{:?}", stats.median)).green(),
+                        format!("mean {:>12}", format!("{:?}", stats.mean)).green(),
+                        format!("± {:?}", stats.stddev).dimmed(),
+                    );
+                }
+
+                let record = BenchRecord::new(
+                    entry.year,
+                    entry.name,
+                    part,
+                    &kind,
+                    bench_args.runs.max(1),
+                    &stats,
+                    commit.clone(),
+                );
+                args.format.emit(&record);
+                records.push(record);
+            }
+        }
+
+        if let Some(path) = &bench_args.output {
+            match bench::write_records(path, &records) {
+                Ok(()) if args.format.is_human() => {
+                    println!("Wrote results to {}", path.display().to_string().cyan())
+                }
+                Ok(()) => {}
+                Err(msg) => {
+                    eprintln!("{}", msg.red());
+                    ok = false;
+                }
+            }
+        }
+
+        ok
+    }
+
+    /// Puts the real input for every registered puzzle whose day is in `days`
+    /// into its `data` directory, getting it from `cache` (which only downloads
+    /// the inputs it doesn't already have). Days that already have an input are
+    /// left alone, in case it was edited by hand, unless `force` is set.
+    ///
+    /// Returns `false` if any input couldn't be fetched or saved.
+    pub fn fetch(
+        &self,
+        days: &DaySelection,
+        cache: &InputCache<impl FetchInput>,
+        force: bool,
+    ) -> bool {
+        let Some(selected) = self.select(days) else {
+            return false;
+        };
+
+        let mut ok = true;
+        for entry in selected {
+            let Some(day) = entry.day() else {
+                continue;
+            };
+
+            let label = format!("Puzzle {} input", entry.name).blue();
+            let path = self.data_dir(entry).join("input");
+            if path.exists() && !force {
+                println!(
+                    "{label} {}",
+                    "(already there; pass --force to replace it)".dimmed()
+                );
+                continue;
+            }
+
+            let year = entry.year;
+            let was_cached = cache.cached(year, day).is_some();
+            let saved = cache
+                .get(year, day)
+                .map_err(|err| err.to_string())
+                .and_then(|text| {
+                    fs::write(&path, text)
+                        .map_err(|err| format!("could not write {}: {err}", path.display()))
+                });
+
+            match saved {
+                Ok(()) if was_cached => println!("{label} {}", "(cached)".dimmed()),
+                Ok(()) => println!("{label} {}", "(downloaded)".dimmed()),
+                Err(msg) => {
+                    eprintln!("{label} {}", msg.red());
+                    ok = false;
+                }
+            }
+        }
+
+        ok
+    }
+
+    /// All of the registered puzzles whose day is in `days`, or `None`
+    /// (after complaining) if there aren't any.
+    fn select(&self, days: &DaySelection) -> Option<Vec<&PuzzleEntry>> {
+        let selected = self
+            .entries()
+            .filter(|entry| entry.day().is_some_and(|day| days.contains(day)))
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            eprintln!("{}", "No puzzles found for the given days.".red());
+            return None;
+        }
+
+        Some(selected)
+    }
+
+    fn run_entry(&self, entry: &PuzzleEntry, args: &PuzzleArgs) -> Result<Vec<PartResult>, String> {
+        entry.check_params(args.part, &args.params)?;
+
+        let data_dir = self.data_dir(entry);
+        let answers = ExpectedAnswers::load(&data_dir)?;
+
+        entry
+            .run(args, &data_dir, &answers)
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// Reports that a puzzle couldn't be run at all.
+fn report_error(entry: &PuzzleEntry, msg: &str, args: &PuzzleArgs) {
+    if args.format.is_human() {
+        println!("{} {}", format!("Puzzle {}:", entry.name).blue(), msg.red());
+    }
+
+    args.format.emit(&ErrorRecord {
+        year: entry.year,
+        day: entry.name,
+        error: msg,
+    });
+}
+
+/// A note about which example was solved, if any, to follow a puzzle's name.
+fn example_note(kind: &InputKind) -> String {
+    match kind {
+        InputKind::Example(name) => format!(" (example {name})"),
+        _ => String::new(),
+    }
+}
+
+fn print_summary(results: &[DayResult]) {
+    const COL_WIDTH: usize = 32;
+
+    // one row for each input that was solved, labelled with the day (and example name)
+    let mut rows: Vec<(String, Result<Vec<&PartResult>, &String>)> = vec![];
+    for result in results {
+        match &result.outcome {
+            Ok(parts) => {
+                for part in parts {
+                    let label = match &part.input {
+                        InputKind::Example(name) => format!("{}/{name}", result.name),
+                        _ => result.name.to_string(),
+                    };
+
+                    match rows.iter_mut().find(|(l, _)| *l == label) {
+                        Some((_, Ok(row))) => row.push(part),
+                        _ => rows.push((label, Ok(vec![part]))),
+                    }
+                }
+            }
+            Err(msg) => rows.push((result.name.to_string(), Err(msg))),
+        }
+    }
+
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| label.len() + 2)
+        .max()
+        .unwrap_or(0)
+        .max(5);
+
+    println!(
+        "{}",
+        format!(
+            "{:<label_width$}{:<COL_WIDTH$}{:<COL_WIDTH$}",
+            "Day", "Part A", "Part B"
+        )
+        .bold()
+    );
+
+    for (label, row) in rows {
+        let cells = match row {
+            Ok(parts) => [PuzzlePartName::A, PuzzlePartName::B]
+                .iter()
+                .map(|&name| match parts.iter().find(|part| part.part == name) {
+                    Some(part) => summary_cell(part),
+                    None => format!("{:COL_WIDTH$}", ""),
+                })
+                .collect::<Vec<_>>()
+                .join(""),
+            Err(msg) => msg.red().to_string(),
+        };
+
+        println!("{}{cells}", format!("{label:<label_width$}").blue());
+    }
+}
+
+fn summary_cell(part: &PartResult) -> String {
+    let mark = match part.check {
+        Some(CheckOutcome::Pass) => "✔ ".green(),
+        Some(CheckOutcome::Fail { .. }) => "✘ ".red(),
+        Some(CheckOutcome::Unknown) => "? ".yellow(),
+        None => "  ".normal(),
+    };
+    let solution = match &part.solution {
+        Ok(Answer::Multiline(_)) => format!("{:<16}", "(multi-line)").magenta(),
+        Ok(Answer::Unsolved) => format!("{:<16}", "Unsolved!").yellow(),
+        Ok(solution) => format!("{solution:<16}").magenta(),
+        Err(_) => format!("{:<16}", "(failed)").red(),
+    };
+    let duration = format!("{:<14}", format!("{:?}", part.duration)).green();
+    format!("{mark}{solution}{duration}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{FetchError, PartEntry};
+    use clap::Parser;
+
+    struct FakeFetcher;
+
+    impl FetchInput for FakeFetcher {
+        fn fetch(&self, _year: u32, day: u32) -> Result<String, FetchError> {
+            Ok(format!("input for day {day}\n"))
+        }
+    }
+
+    fn entry(name: &'static str) -> PuzzleEntry {
+        let part = PartEntry {
+            description: || "",
+            param_names: || &[],
+            solve: |_, _| Ok(Answer::Unsolved),
+        };
+        PuzzleEntry {
+            name,
+            year: 2024,
+            part_a: part,
+            part_b: part,
+        }
+    }
+
+    #[test]
+    fn should_parse_day_selections() {
+        assert_eq!("6".parse(), Ok(DaySelection(6..=6)));
+        assert_eq!("1..=25".parse(), Ok(DaySelection(1..=25)));
+        assert_eq!("3..8".parse(), Ok(DaySelection(3..=7)));
+    }
+
+    #[test]
+    fn should_reject_bad_day_selections() {
+        assert!("six".parse::<DaySelection>().is_err());
+        assert!("8..3".parse::<DaySelection>().is_err());
+        assert!("1..1".parse::<DaySelection>().is_err());
+        assert!("0..0".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn should_only_read_stdin_for_one_day() {
+        let registry = Registry::new("/nonexistent", vec![entry("01"), entry("02")]);
+        let args = PuzzleArgs::parse_from(["aoc", "--input", "-"]);
+
+        assert!(!registry.run(&DaySelection::all(), &args));
+        assert!(args.validate(1).is_ok());
+        assert!(args.validate(2).is_err());
+    }
+
+    #[test]
+    fn should_not_replace_existing_input_unless_forced() {
+        let root = std::env::temp_dir().join(format!("aoc-registry-{}", std::process::id()));
+        let registry = Registry::new(&root, vec![entry("01"), entry("02")]);
+        let cache = InputCache::new(root.join("cache"), FakeFetcher);
+        for day in ["01", "02"] {
+            fs::create_dir_all(root.join("2024").join(day).join("data")).unwrap();
+        }
+        let edited = root.join("2024/01/data/input");
+        fs::write(&edited, "edited by hand\n").unwrap();
+
+        assert!(registry.fetch(&DaySelection::all(), &cache, false));
+        assert_eq!(fs::read_to_string(&edited).unwrap(), "edited by hand\n");
+        assert_eq!(
+            fs::read_to_string(root.join("2024/02/data/input")).unwrap(),
+            "input for day 2\n"
+        );
+
+        assert!(registry.fetch(&DaySelection::all(), &cache, true));
+        assert_eq!(fs::read_to_string(&edited).unwrap(), "input for day 1\n");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,159 @@
+use super::{bench, Answer, History, HistoryEntry, OutputFormat, PuzzlePartName};
+use clap::ValueEnum;
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, IsTerminal, Write},
+    path::Path,
+    time::Duration,
+};
+
+/// Somewhere to put the answers to a puzzle's main input, besides printing them.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnswerSink {
+    /// Write each part's latest answer to `data/soln-<part>`
+    File,
+
+    /// Don't put answers anywhere, just print them
+    Stdout,
+
+    /// Copy the answer to the clipboard, through the terminal (with an OSC 52 escape code)
+    Clipboard,
+
+    /// Append every answer to `data/history.jsonl`, along with how long it took
+    /// and the current git commit
+    History,
+}
+
+/// Where answers go when no `--sink` is given.
+pub const DEFAULT_SINKS: &[AnswerSink] = &[AnswerSink::File, AnswerSink::History];
+
+/// An answer to a puzzle's main input, ready to be recorded.
+#[derive(Clone, Copy, Debug)]
+pub struct SolvedAnswer<'a> {
+    pub part: PuzzlePartName,
+    pub answer: &'a Answer,
+    pub duration: Duration,
+}
+
+impl AnswerSink {
+    /// Whether this sink puts answers where they're picked up for submitting,
+    /// which is no place for an answer that can't be right.
+    pub fn is_for_submitting(&self) -> bool {
+        matches!(self, Self::File | Self::Clipboard)
+    }
+
+    /// Puts an answer for the puzzle whose data lives in `data_dir` into this sink,
+    /// while the runner's results are printed in `format`.
+    pub fn record(
+        &self,
+        data_dir: &Path,
+        solved: &SolvedAnswer,
+        format: OutputFormat,
+    ) -> Result<(), String> {
+        let part = solved.part.to_string().to_lowercase();
+        match self {
+            Self::File => {
+                let path = data_dir.join(format!("soln-{part}"));
+                fs::write(&path, solved.answer.to_string())
+                    .map_err(|err| format!("could not write {}: {err}", path.display()))
+            }
+            Self::Stdout => Ok(()),
+            Self::Clipboard => copy_to_clipboard(&solved.answer.to_string(), format)
+                .map_err(|err| format!("could not copy part {part}'s answer: {err}")),
+            Self::History => {
+                let entry = HistoryEntry {
+                    duration_ns: Some(solved.duration.as_nanos() as u64),
+                    commit: bench::current_commit(),
+                    ..HistoryEntry::new(solved.part, solved.answer.clone())
+                };
+                History::append(data_dir, &entry)
+            }
+        }
+    }
+}
+
+/// Asks the terminal to put `text` on the clipboard, which works over SSH and
+/// without any X11 tools (as long as the terminal supports it). Writes to the
+/// controlling terminal if stdout isn't one, or if stdout is for machine-readable
+/// records, so as not to mess up the output.
+fn copy_to_clipboard(text: &str, format: OutputFormat) -> io::Result<()> {
+    let code = format!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+
+    if format.is_human() && io::stdout().is_terminal() {
+        let mut stdout = io::stdout();
+        stdout.write_all(code.as_bytes())?;
+        stdout.flush()
+    } else {
+        OpenOptions::new()
+            .write(true)
+            .open("/dev/tty")?
+            .write_all(code.as_bytes())
+    }
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"4758"), "NDc1OA==");
+        assert_eq!(base64(b"6,1"), "Niwx");
+        assert_eq!(base64(b"co,de,ka,ta"), "Y28sZGUsa2EsdGE=");
+    }
+
+    #[test]
+    fn should_record_to_file_and_history() {
+        let data_dir = std::env::temp_dir().join(format!("aoc-sink-{}", std::process::id()));
+        fs::create_dir_all(&data_dir).unwrap();
+
+        let answer = Answer::Integer(41);
+        let solved = SolvedAnswer {
+            part: PuzzlePartName::B,
+            answer: &answer,
+            duration: Duration::ZERO,
+        };
+        for _ in 0..2 {
+            AnswerSink::File
+                .record(&data_dir, &solved, OutputFormat::Json)
+                .unwrap();
+            AnswerSink::History
+                .record(&data_dir, &solved, OutputFormat::Json)
+                .unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(data_dir.join("soln-b")).unwrap(), "41");
+        let history = History::load(&data_dir).unwrap();
+        assert_eq!(history.entries().count(), 2);
+        assert!(history
+            .entries()
+            .all(|entry| entry.part == PuzzlePartName::B
+                && entry.answer == answer
+                && entry.duration_ns == Some(0)));
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+use super::{
+    example_names, ExpectedAnswers, InputKind, InputSource, ParamSettings, Puzzle, PuzzlePartName,
+};
+use std::path::Path;
+
+/// Generates a `#[test]` for each part of a puzzle, which solves each of the puzzle's
+/// example inputs and compares the solutions to the example answers recorded in
+/// `data/answers.toml`. Examples without a recorded answer for the part are skipped,
+/// but each test fails unless at least one example was checked.
+///
+/// Call it with the name of the puzzle type, eg. `example_tests!(Puzzle06);`. A part
+/// that has no example answers at all can be ignored, with a reason:
+/// ```ignore
+/// example_tests!(Puzzle25, b: ignore = "there is no part B");
+/// ```
+/// New days start with both parts ignored, until their example answers are recorded.
+#[macro_export]
+macro_rules! example_tests {
+    ($puzzle:ident) => {
+        $crate::example_tests!(@tests $puzzle, [], []);
+    };
+    ($puzzle:ident, a: ignore = $reason:literal) => {
+        $crate::example_tests!(@tests $puzzle, [$reason], []);
+    };
+    ($puzzle:ident, b: ignore = $reason:literal) => {
+        $crate::example_tests!(@tests $puzzle, [], [$reason]);
+    };
+    ($puzzle:ident, a: ignore = $reason_a:literal, b: ignore = $reason_b:literal) => {
+        $crate::example_tests!(@tests $puzzle, [$reason_a], [$reason_b]);
+    };
+    (@tests $puzzle:ident, [$($reason_a:literal)?], [$($reason_b:literal)?]) => {
+        #[cfg(test)]
+        mod example_tests {
+            use $crate::puzzle::{assert_example_answer, PuzzlePartName};
+
+            const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+            #[test]
+            $(#[ignore = $reason_a])?
+            fn should_solve_example_part_a() {
+                assert_example_answer::<super::$puzzle>(DATA_DIR, PuzzlePartName::A);
+            }
+
+            #[test]
+            $(#[ignore = $reason_b])?
+            fn should_solve_example_part_b() {
+                assert_example_answer::<super::$puzzle>(DATA_DIR, PuzzlePartName::B);
+            }
+        }
+    };
+}
+
+/// Solves one part of a puzzle using each example input (and example parameters)
+/// in `data_dir`, and panics if any solution doesn't match its recorded answer, or
+/// if none of the examples has a recorded answer for the part.
+#[track_caller]
+pub fn assert_example_answer<P: Puzzle>(data_dir: &str, part: PuzzlePartName) {
+    let data_dir = Path::new(data_dir);
+    let answers = ExpectedAnswers::load(data_dir).unwrap();
+    let names = example_names(data_dir).unwrap_or_else(|err| panic!("{err}"));
+
+    let mut checked = 0;
+    for name in names {
+        let kind = InputKind::Example(name.clone());
+        let Some(expected) = answers.get(part, &kind) else {
+            // nothing to compare against, and some solvers never finish on
+            // examples meant for the other part, so don't bother solving
+            continue;
+        };
+
+        let input = InputSource::of(data_dir, &kind).read().unwrap();
+        let solution = (P::entry().part(part).solve)(&input, &ParamSettings::example())
+            .unwrap_or_else(|err| panic!("puzzle {} part {part} failed: {err}", P::name()));
+
+        assert!(
+            solution.matches(&expected),
+            "wrong answer for puzzle {} part {part} with example {name}: expected {expected}, got {solution}",
+            P::name()
+        );
+        checked += 1;
+    }
+
+    assert!(
+        checked > 0,
+        "no example for puzzle {} part {part} has an answer in {}",
+        P::name(),
+        data_dir.join("answers.toml").display()
+    );
+}
@@ -0,0 +1,428 @@
+//! Shortest path searches over any kind of state, like grid points or
+//! (point, direction) pairs. Each search starts from a single state, and gets
+//! the states that can be reached from a state with a successor function.
+//! It stops as soon as it reaches a state that `is_goal` accepts, or once
+//! every reachable state has been visited (if `is_goal` never accepts any).
+//!
+//! The `_all` versions of the searches also record every way of reaching each
+//! state by a cheapest path, for finding all of the states on those paths, or
+//! counting them. They need every step to cost more than 0.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// What a search found out about the states it reached.
+#[derive(Clone, Debug)]
+pub struct SearchResult<S> {
+    /// The cost of the cheapest known path to each state reached. These are
+    /// exact for every state that was visited before the search stopped.
+    pub distances: HashMap<S, u64>,
+
+    /// The state before each state (besides the start) on its cheapest known path
+    pub predecessors: HashMap<S, S>,
+
+    /// Every state that comes right before each state on one of its cheapest
+    /// known paths, if the search recorded them
+    pub all_predecessors: Option<HashMap<S, Vec<S>>>,
+
+    /// The goal state that the search stopped at, if it found one
+    pub goal: Option<S>,
+}
+
+impl<S: Hash + Eq + Clone> SearchResult<S> {
+    fn new(start: S, record_all: bool) -> Self {
+        Self {
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+            all_predecessors: record_all.then(HashMap::new),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// The cost of the path to the goal, if one was found.
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// The cheapest known path from the start to `state`, including both ends.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path from the start to the goal, if one was found.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    /// Every state on any of the cheapest paths from the start to `state`,
+    /// including both ends (which is nothing if `state` wasn't reached).
+    ///
+    /// Panics if the search didn't record all predecessors.
+    pub fn on_shortest_paths(&self, state: &S) -> HashSet<S> {
+        let all_predecessors = self.expect_all_predecessors();
+        if !self.distances.contains_key(state) {
+            return HashSet::new();
+        }
+
+        let mut found = HashSet::from([state.clone()]);
+        let mut pending = vec![state];
+        while let Some(state) = pending.pop() {
+            for prev in all_predecessors.get(state).into_iter().flatten() {
+                if found.insert(prev.clone()) {
+                    pending.push(prev);
+                }
+            }
+        }
+
+        found
+    }
+
+    /// How many different cheapest paths there are from the start to `state`
+    /// (saturating at `u64::MAX`).
+    ///
+    /// Panics if the search didn't record all predecessors.
+    pub fn count_shortest_paths(&self, state: &S) -> u64 {
+        let all_predecessors = self.expect_all_predecessors();
+
+        // every predecessor is cheaper to reach, so counting the paths to
+        // the cheapest states first means their counts are ready when needed
+        let mut states = self
+            .on_shortest_paths(state)
+            .into_iter()
+            .collect::<Vec<_>>();
+        states.sort_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<&S, u64> = HashMap::new();
+        for state in &states {
+            let count = match all_predecessors.get(state) {
+                None => 1,
+                Some(prevs) => prevs
+                    .iter()
+                    .map(|prev| counts[prev])
+                    .fold(0, u64::saturating_add),
+            };
+            counts.insert(state, count);
+        }
+
+        counts.get(state).copied().unwrap_or(0)
+    }
+
+    fn expect_all_predecessors(&self) -> &HashMap<S, Vec<S>> {
+        self.all_predecessors
+            .as_ref()
+            .expect("the search should record all predecessors (with `bfs_all` or `dijkstra_all`)")
+    }
+
+    /// Notes a path to `next` through `prev`, if it's cheaper than any path
+    /// known so far (or as cheap, if recording all predecessors). Returns
+    /// whether it was cheaper, in which case `next` needs to be visited again.
+    fn relax(&mut self, prev: &S, next: &S, cost: u64) -> bool {
+        match self.distance(next) {
+            Some(known) if known < cost => return false,
+            Some(known) if known == cost => {
+                if let Some(all) = &mut self.all_predecessors {
+                    all.entry(next.clone()).or_default().push(prev.clone());
+                }
+                return false;
+            }
+            _ => {}
+        }
+
+        self.distances.insert(next.clone(), cost);
+        self.predecessors.insert(next.clone(), prev.clone());
+        if let Some(all) = &mut self.all_predecessors {
+            all.insert(next.clone(), vec![prev.clone()]);
+        }
+        true
+    }
+}
+
+/// Breadth-first search, for when every step costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, is_goal, false)
+}
+
+/// Breadth-first search that records every shortest path.
+pub fn bfs_all<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, is_goal, true)
+}
+
+fn breadth_first<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    record_all: bool,
+) -> SearchResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new(start.clone(), record_all);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+
+        let cost = result.distances[&state] + 1;
+        for next in successors(&state) {
+            if result.relax(&state, &next, cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm, for steps with different (non-negative) costs.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    best_first(start, successors, is_goal, |_| 0, false)
+}
+
+/// Dijkstra's algorithm that records every cheapest path.
+pub fn dijkstra_all<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    best_first(start, successors, is_goal, |_| 0, true)
+}
+
+/// A*, which is Dijkstra's algorithm guided towards the goal by a heuristic
+/// that estimates the cost from a state to the goal. The heuristic must never
+/// overestimate, or the path found might not be the cheapest.
+pub fn a_star<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> u64,
+) -> SearchResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    best_first(start, successors, is_goal, heuristic, false)
+}
+
+fn best_first<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> u64,
+    record_all: bool,
+) -> SearchResult<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut result = SearchResult::new(start.clone(), record_all);
+    let mut open = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    }]);
+
+    while let Some(Queued { cost, state, .. }) = open.pop() {
+        // a cheaper path to this state was found after this one was queued
+        if result.distance(&state).is_some_and(|known| known < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if result.relax(&state, &next, next_cost) {
+                open.push(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    result
+}
+
+/// A state waiting to be visited, which is ordered so that the `BinaryHeap`
+/// (a max-heap) gives back the lowest priority first, breaking ties in favor
+/// of the state that's furthest along (and hopefully closest to the goal).
+struct Queued<S> {
+    priority: u64,
+    cost: u64,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.priority, self.cost) == (other.priority, other.cost)
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then(self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A little weighted graph:
+    /// ```text
+    /// a --1-- b --1-- c
+    ///  \             /
+    ///   -----5------
+    /// ```
+    fn edges(state: &char) -> Vec<(char, u64)> {
+        match state {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('a', 5), ('b', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn should_find_fewest_steps() {
+        let result = bfs('a', |s| edges(s).into_iter().map(|(s, _)| s), |s| *s == 'c');
+
+        assert_eq!(result.goal, Some('c'));
+        assert_eq!(result.goal_distance(), Some(1));
+        assert_eq!(result.goal_path(), Some(vec!['a', 'c']));
+    }
+
+    #[test]
+    fn should_find_cheapest_path() {
+        let result = dijkstra('a', edges, |s| *s == 'c');
+
+        assert_eq!(result.goal_distance(), Some(2));
+        assert_eq!(result.goal_path(), Some(vec!['a', 'b', 'c']));
+        assert_eq!(result.predecessors.get(&'b'), Some(&'a'));
+    }
+
+    #[test]
+    fn should_explore_everything_without_a_goal() {
+        let result = dijkstra('a', edges, |_| false);
+
+        assert_eq!(result.goal, None);
+        assert_eq!(result.distances.len(), 3);
+        assert_eq!(result.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(result.path_to(&'z'), None);
+    }
+
+    #[test]
+    fn should_find_every_shortest_path() {
+        // a diamond (a -> b or c -> d) with a tail (d -> e), and a dead end (a -> x)
+        let successors = |state: &char| match state {
+            'a' => vec![('b', 1), ('c', 1), ('x', 1)],
+            'b' | 'c' => vec![('d', 1)],
+            'd' => vec![('e', 2)],
+            _ => vec![],
+        };
+        let result = dijkstra_all('a', successors, |_| false);
+
+        assert_eq!(result.distance(&'e'), Some(4));
+        assert_eq!(result.count_shortest_paths(&'e'), 2);
+        assert_eq!(result.count_shortest_paths(&'a'), 1);
+        assert_eq!(result.count_shortest_paths(&'z'), 0);
+        assert_eq!(
+            result.on_shortest_paths(&'e'),
+            HashSet::from(['a', 'b', 'c', 'd', 'e'])
+        );
+
+        let unweighted = |state: &char| successors(state).into_iter().map(|(s, _)| s);
+        let result = bfs_all('a', unweighted, |s| *s == 'd');
+        assert_eq!(result.goal_distance(), Some(2));
+        assert_eq!(result.count_shortest_paths(&'d'), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "record all predecessors")]
+    fn should_need_all_predecessors_to_count() {
+        dijkstra('a', edges, |_| false).count_shortest_paths(&'c');
+    }
+
+    #[test]
+    fn should_search_grid_with_heuristic() {
+        // an open 10x10 grid, where only moving right or down is allowed
+        let successors = |&(r, c): &(i64, i64)| {
+            [(r + 1, c), (r, c + 1)]
+                .into_iter()
+                .filter(|&(r, c)| r < 10 && c < 10)
+                .map(|s| (s, 1))
+        };
+        let goal = (9, 9);
+        let manhattan = |&(r, c): &(i64, i64)| (goal.0 - r + goal.1 - c) as u64;
+
+        let guided = a_star((0, 0), successors, |s| *s == goal, manhattan);
+        let unguided = dijkstra((0, 0), successors, |s| *s == goal);
+
+        assert_eq!(guided.goal_distance(), Some(18));
+        assert_eq!(guided.goal_path().map(|path| path.len()), Some(19));
+        assert!(guided.distances.len() < unguided.distances.len());
+    }
+}
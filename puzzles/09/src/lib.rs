@@ -1,2 +0,0 @@
-pub mod puzzle09a;
-pub mod puzzle09b;
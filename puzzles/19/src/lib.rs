@@ -1,2 +0,0 @@
-pub mod puzzle19a;
-pub mod puzzle19b;
@@ -0,0 +1,42 @@
+use common::puzzle::{Context, PuzzleError};
+
+pub mod puzzle01a;
+pub mod puzzle01b;
+
+pub struct Puzzle01 {}
+
+impl common::puzzle::Puzzle for Puzzle01 {
+    type PartA = puzzle01a::Puzzle01a;
+    type PartB = puzzle01b::Puzzle01b;
+
+    fn name() -> &'static str {
+        "01"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle01);
+
+pub fn parse_two_lists(input: &str) -> Result<(Vec<u64>, Vec<u64>), PuzzleError> {
+    let (mut left, mut right) = (vec![], vec![]);
+
+    for (idx, line) in input.lines().enumerate() {
+        let mut nums = line.split_whitespace();
+        let mut next_num = || -> Result<u64, PuzzleError> {
+            let num = nums.next().context("expected two numbers")?.parse()?;
+            Ok(num)
+        };
+
+        left.push(next_num().map_err(|err| err.at_line(idx + 1))?);
+        right.push(next_num().map_err(|err| err.at_line(idx + 1))?);
+    }
+
+    Ok((left, right))
+}
+
+pub fn count_occurrences(target: u64, list: &[u64]) -> usize {
+    list.iter().filter(|x| **x == target).count()
+}
@@ -1,23 +1,26 @@
 use crate::parse_two_lists;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle01a {}
 
 impl PuzzlePart for Puzzle01a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Sum the (absolute) differences between correspondingly ordered elements of two lists."
     }
 
-    fn solve(input: &str) -> String {
-        let (mut left, mut right) = parse_two_lists(input);
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (mut left, mut right) = parse_two_lists(input)?;
 
         left.sort_unstable();
         right.sort_unstable();
 
-        left.iter()
+        Ok(left
+            .iter()
             .zip(right.iter())
             .map(|(left, right)| left.abs_diff(*right))
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 }
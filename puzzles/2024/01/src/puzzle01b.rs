@@ -1,21 +1,24 @@
 use crate::{count_occurrences, parse_two_lists};
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle01b {}
 
 impl PuzzlePart for Puzzle01b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Sum the 'similarity scores' of numbers in two lists."
     }
 
-    fn solve(input: &str) -> String {
-        let (left, right) = parse_two_lists(input);
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (left, right) = parse_two_lists(input)?;
 
-        left.iter()
+        Ok(left
+            .iter()
             // similarity score is the product of the value and
             // the number of times it appears in the other list
             .map(|x| count_occurrences(*x, &right) * (*x as usize))
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 }
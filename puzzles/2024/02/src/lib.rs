@@ -1,16 +1,44 @@
+use common::puzzle::PuzzleError;
+
 pub mod puzzle02a;
 pub mod puzzle02b;
 
+pub struct Puzzle02 {}
+
+impl common::puzzle::Puzzle for Puzzle02 {
+    type PartA = puzzle02a::Puzzle02a;
+    type PartB = puzzle02b::Puzzle02b;
+
+    fn name() -> &'static str {
+        "02"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle02);
+
 #[derive(Debug)]
 pub struct Report(Vec<i32>);
 
 impl Report {
-    pub fn parse_from_str(line: &str) -> Report {
+    pub fn parse_from_str(line: &str) -> Result<Report, PuzzleError> {
         let vec = line
             .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
-        Report(vec)
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(Report(vec))
+    }
+
+    /// Parses one report per line of the input.
+    pub fn parse_all(input: &str) -> Result<Vec<Report>, PuzzleError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Self::parse_from_str(line).map_err(|err| err.at_line(idx + 1)))
+            .collect()
     }
 
     /// A Report is "safe" if:
@@ -1,19 +1,20 @@
 use crate::Report;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle02a {}
 
 impl PuzzlePart for Puzzle02a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Count the number of 'safe' reports."
     }
 
-    fn solve(input: &str) -> String {
-        input
-            .lines()
-            .map(Report::parse_from_str)
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(Report::parse_all(input)?
+            .into_iter()
             .filter(Report::is_safe)
             .count()
-            .to_string()
+            .into())
     }
 }
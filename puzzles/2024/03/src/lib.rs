@@ -1,6 +1,23 @@
 pub mod puzzle03a;
 pub mod puzzle03b;
 
+pub struct Puzzle03 {}
+
+impl common::puzzle::Puzzle for Puzzle03 {
+    type PartA = puzzle03a::Puzzle03a;
+    type PartB = puzzle03b::Puzzle03b;
+
+    fn name() -> &'static str {
+        "03"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle03);
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Mul(u64, u64);
 
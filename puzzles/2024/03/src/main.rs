@@ -0,0 +1,9 @@
+use common::puzzle::{Puzzle, TrackingAllocator};
+use puzzle2024_03::Puzzle03;
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+fn main() {
+    Puzzle03::run(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -1,17 +1,19 @@
 use crate::{parse_instructions, Mul};
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle03b {}
 
 impl PuzzlePart for Puzzle03b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Sum the *enabled* 'mul' expressions found in a corrupted string"
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         let mut enabled = true;
 
-        parse_instructions(input)
+        Ok(parse_instructions(input)
             .iter()
             .filter_map(|inst| match inst {
                 crate::Instruction::Do => {
@@ -32,6 +34,6 @@ impl PuzzlePart for Puzzle03b {
             })
             .map(|Mul(x, y)| *x * *y)
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 }
@@ -1,8 +1,26 @@
-use common::{grid_def, grid::Grid, point::Point};
+use common::{dir::Dir, grid::Grid, grid_def, point::Point};
+use std::iter;
 
 pub mod puzzle04a;
 pub mod puzzle04b;
 
+pub struct Puzzle04 {}
+
+impl common::puzzle::Puzzle for Puzzle04 {
+    type PartA = puzzle04a::Puzzle04a;
+    type PartB = puzzle04b::Puzzle04b;
+
+    fn name() -> &'static str {
+        "04"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle04);
+
 grid_def!(XmasGrid, char);
 
 impl XmasGrid {
@@ -10,6 +28,29 @@ impl XmasGrid {
         Self(Grid::parse(input))
     }
 
+    /// Counts the times `word` appears in a straight line, in any of the eight directions.
+    pub fn count_word(&self, word: &str) -> usize {
+        let mut chars = word.chars();
+        let Some(first) = chars.next() else {
+            return 0;
+        };
+        let rest = chars.collect::<Vec<_>>();
+
+        self.positions_of(&first)
+            .map(|start| {
+                Dir::all8()
+                    .into_iter()
+                    .filter(|dir| {
+                        iter::successors(Some(start), |pt| Some(pt + dir.step()))
+                            .skip(1)
+                            .zip(&rest)
+                            .all(|(pt, c)| self.at(pt) == Some(c))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
     fn matches(&self, pattern: &Grid<char>, start: Point) -> bool {
         for row_offset in 0..pattern.height() {
             for col_offset in 0..pattern.width() {
@@ -0,0 +1,9 @@
+use common::puzzle::{Puzzle, TrackingAllocator};
+use puzzle2024_04::Puzzle04;
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+fn main() {
+    Puzzle04::run(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -0,0 +1,16 @@
+use crate::XmasGrid;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle04a {}
+
+impl PuzzlePart for Puzzle04a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Count the number of times 'XMAS' appears in a grid."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(XmasGrid::parse(input).count_word("XMAS").into())
+    }
+}
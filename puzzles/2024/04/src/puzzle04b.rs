@@ -1,5 +1,8 @@
 use crate::XmasGrid;
-use common::{grid::Grid, puzzle::PuzzlePart};
+use common::{
+    grid::Grid,
+    puzzle::{Answer, PuzzleError, PuzzlePart},
+};
 
 pub struct Puzzle04b {}
 
@@ -11,18 +14,20 @@ const PATTERNS: [&str; 4] = [
 ];
 
 impl PuzzlePart for Puzzle04b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Count the number of times X's of 'MAS' appear in a grid."
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         let grid = XmasGrid::parse(input);
 
-        PATTERNS
+        Ok(PATTERNS
             .into_iter()
             .map(Grid::parse)
             .map(|pattern| grid.count_matches(&pattern))
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 }
@@ -1,8 +1,26 @@
+use common::puzzle::{Context, PuzzleError};
 use std::cmp::Ordering;
 
 pub mod puzzle05a;
 pub mod puzzle05b;
 
+pub struct Puzzle05 {}
+
+impl common::puzzle::Puzzle for Puzzle05 {
+    type PartA = puzzle05a::Puzzle05a;
+    type PartB = puzzle05b::Puzzle05b;
+
+    fn name() -> &'static str {
+        "05"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle05);
+
 #[derive(Debug)]
 pub struct OrderedPair(u64, u64);
 
@@ -34,31 +52,35 @@ impl Update {
     }
 }
 
-pub fn parse_input(input: &str) -> (Vec<OrderedPair>, Vec<Update>) {
+pub fn parse_input(input: &str) -> Result<(Vec<OrderedPair>, Vec<Update>), PuzzleError> {
     let Some((pair_str, updates_str)) = input.split_once("\n\n") else {
-        return (vec![], vec![]);
+        return Ok((vec![], vec![]));
     };
 
     let pairs = pair_str
         .lines()
         .map(|line| {
-            let (left, right) = line.split_once('|').unwrap();
-            let left = left.parse().unwrap();
-            let right = right.parse().unwrap();
-            OrderedPair(left, right)
+            let parse_pair = || -> Result<_, PuzzleError> {
+                let (left, right) = line
+                    .split_once('|')
+                    .context("expected a pair like \"1|2\"")?;
+                Ok(OrderedPair(left.parse()?, right.parse()?))
+            };
+
+            parse_pair().map_err(|err| err.at_fragment(input, line))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     let updates = updates_str
         .lines()
         .map(|line| {
             line.split(',')
                 .map(str::parse)
-                .map(Result::unwrap)
-                .collect()
+                .collect::<Result<_, _>>()
+                .map(Update)
+                .map_err(|err| PuzzleError::from(err).at_fragment(input, line))
         })
-        .map(Update)
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    (pairs, updates)
+    Ok((pairs, updates))
 }
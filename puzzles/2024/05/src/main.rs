@@ -0,0 +1,9 @@
+use common::puzzle::{Puzzle, TrackingAllocator};
+use puzzle2024_05::Puzzle05;
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+fn main() {
+    Puzzle05::run(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+}
@@ -1,21 +1,23 @@
 use crate::{parse_input, Update};
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle05a {}
 
 impl PuzzlePart for Puzzle05a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Sum the middle numbers of correctly-ordered lists."
     }
 
-    fn solve(input: &str) -> String {
-        let (pairs, updates) = parse_input(input);
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (pairs, updates) = parse_input(input)?;
 
-        updates
+        Ok(updates
             .iter()
             .filter(|update| update.is_sorted_by(&pairs))
             .map(Update::middle)
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 }
@@ -1,18 +1,20 @@
 use crate::parse_input;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle05b {}
 
 impl PuzzlePart for Puzzle05b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Sum the middle numbers of incorrectly-ordered lists (after ordering them)."
     }
 
-    fn solve(input: &str) -> String {
-        let (pairs, mut updates) = parse_input(input);
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (pairs, mut updates) = parse_input(input)?;
 
         #[expect(clippy::manual_inspect)]
-        updates
+        let total = updates
             .iter_mut()
             .filter(|update| !update.is_sorted_by(&pairs))
             .map(|update| {
@@ -20,7 +22,8 @@ impl PuzzlePart for Puzzle05b {
                 update
             })
             .map(|update| update.middle())
-            .sum::<u64>()
-            .to_string()
+            .sum::<u64>();
+
+        Ok(total.into())
     }
 }
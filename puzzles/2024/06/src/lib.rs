@@ -1,9 +1,32 @@
-use common::{dir::Dir, grid::Grid, grid_def, point::Point};
+use common::{
+    dir::Dir,
+    grid::Grid,
+    grid_def,
+    point::Point,
+    puzzle::{Context, PuzzleError},
+};
 use std::collections::HashSet;
 
 pub mod puzzle06a;
 pub mod puzzle06b;
 
+pub struct Puzzle06 {}
+
+impl common::puzzle::Puzzle for Puzzle06 {
+    type PartA = puzzle06a::Puzzle06a;
+    type PartB = puzzle06b::Puzzle06b;
+
+    fn name() -> &'static str {
+        "06"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle06);
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Tile {
     Nothing,
@@ -22,28 +45,15 @@ impl From<char> for Tile {
     }
 }
 
-trait TurnRight {
-    fn turn_right(&mut self);
-}
-
-impl TurnRight for Dir {
-    fn turn_right(&mut self) {
-        *self = match self {
-            Dir::North => Self::East,
-            Dir::South => Self::West,
-            Dir::East => Self::South,
-            Dir::West => Self::North,
-        }
-    }
-}
-
 grid_def!(GuardGrid, Tile);
 
 impl GuardGrid {
-    pub fn parse(input: &str) -> (Self, Point, Dir) {
-        let grid = Grid::<Tile>::parse(input);
-        let start = grid.find_pt(|t| t == Tile::Guard).unwrap();
-        (GuardGrid(grid), start, Dir::North)
+    pub fn parse(input: &str) -> Result<(Self, Point, Dir), PuzzleError> {
+        let grid = Grid::<Tile>::try_parse_with(input, Tile::from)?;
+        let start = grid
+            .find_pt(|t| t == Tile::Guard)
+            .context("expected a guard ('^') somewhere in the grid")?;
+        Ok((GuardGrid(grid), start, Dir::North))
     }
 
     fn walk(&self, start: Point, dir: Dir) -> impl Iterator<Item = (Point, Dir)> + '_ {
@@ -130,7 +140,7 @@ impl<'a> Iterator for GuardGridWalker<'a> {
 
         // turn right until we're not facing an obstacle
         while self.grid.at(next_pt).is_some_and(|t| *t == Tile::Obstacle) {
-            self.dir.turn_right();
+            self.dir = self.dir.turn_right();
             next_pt = self.pt + self.dir.step();
         }
 
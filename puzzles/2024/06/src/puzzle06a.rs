@@ -0,0 +1,18 @@
+use crate::GuardGrid;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle06a {}
+
+impl PuzzlePart for Puzzle06a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Count the number of spaces that a guard walks over while moving around a room."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (grid, start, dir) = GuardGrid::parse(input)?;
+
+        Ok(grid.walked_points(start, dir).len().into())
+    }
+}
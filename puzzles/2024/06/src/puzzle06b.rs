@@ -0,0 +1,18 @@
+use crate::GuardGrid;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle06b {}
+
+impl PuzzlePart for Puzzle06b {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Count the number of positions in which an added obstruction would cause the guard to walk in a loop."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (grid, start, dir) = GuardGrid::parse(input)?;
+
+        Ok(grid.loop_causing_obstacles(start, dir).into())
+    }
+}
@@ -1,6 +1,25 @@
+use common::puzzle::{Context, PuzzleError};
+
 pub mod puzzle07a;
 pub mod puzzle07b;
 
+pub struct Puzzle07 {}
+
+impl common::puzzle::Puzzle for Puzzle07 {
+    type PartA = puzzle07a::Puzzle07a;
+    type PartB = puzzle07b::Puzzle07b;
+
+    fn name() -> &'static str {
+        "07"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle07);
+
 #[derive(Debug)]
 pub struct Equation {
     lhs: i64,
@@ -8,18 +27,23 @@ pub struct Equation {
 }
 
 impl Equation {
-    pub fn parse(input: &str) -> Self {
-        let (left, right) = input.split_once(": ").unwrap();
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        let (left, right) = input.split_once(": ").context("expected \": \"")?;
 
-        let lhs = left.parse().unwrap();
+        let lhs = left.parse()?;
 
-        let rhs = right
-            .split(' ')
-            .map(str::parse)
-            .map(Result::unwrap)
-            .collect();
+        let rhs = right.split(' ').map(str::parse).collect::<Result<_, _>>()?;
 
-        Equation { lhs, rhs }
+        Ok(Equation { lhs, rhs })
+    }
+
+    /// Parses one equation per line of the input.
+    pub fn parse_all(input: &str) -> Result<Vec<Self>, PuzzleError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Self::parse(line).map_err(|err| err.at_line(idx + 1)))
+            .collect()
     }
 
     fn apply_ops(&self, ops: &[Op]) -> i64 {
@@ -1,22 +1,23 @@
 use crate::{Equation, Op};
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle07b {}
 
 impl PuzzlePart for Puzzle07b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Sum the 'test' values that can be forms by adding/multiplying/concatting other numbers."
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         let ops = vec![Op::Add, Op::Mul, Op::Concat];
 
-        input
-            .lines()
-            .map(Equation::parse)
+        Ok(Equation::parse_all(input)?
+            .into_iter()
             .filter(|eqn| eqn.is_equalable(&ops))
             .map(|eqn| eqn.lhs)
             .sum::<i64>()
-            .to_string()
+            .into())
     }
 }
@@ -6,6 +6,23 @@ use std::collections::{HashMap, HashSet};
 pub mod puzzle08a;
 pub mod puzzle08b;
 
+pub struct Puzzle08 {}
+
+impl common::puzzle::Puzzle for Puzzle08 {
+    type PartA = puzzle08a::Puzzle08a;
+    type PartB = puzzle08b::Puzzle08b;
+
+    fn name() -> &'static str {
+        "08"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle08);
+
 #[derive(Debug, Clone, Copy)]
 pub enum Tile {
     Nothing,
@@ -32,19 +49,11 @@ impl AntennaGrid {
     pub fn antennae(&self) -> HashMap<char, Vec<Point>> {
         let mut out = HashMap::<char, Vec<Point>>::new();
 
-        self.rows().enumerate().for_each(|(row_idx, row)| {
-            row.enumerate().for_each(|(col_idx, tile)| {
-                if let Tile::Antenna(c) = tile {
-                    let pt = (row_idx as i64, col_idx as i64).into();
-                    match out.get_mut(&c) {
-                        Some(vec) => vec.push(pt),
-                        None => {
-                            out.insert(c, vec![pt]);
-                        }
-                    };
-                }
-            })
-        });
+        for (pt, tile) in self.enumerate() {
+            if let Tile::Antenna(c) = tile {
+                out.entry(*c).or_default().push(pt);
+            }
+        }
 
         out
     }
@@ -1,21 +1,24 @@
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 use crate::AntennaGrid;
 
 pub struct Puzzle08a {}
 
 impl PuzzlePart for Puzzle08a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Count the number of 'simple antinodes' formed by pairs of antennae within a grid"
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         let grid = AntennaGrid::parse(input);
 
-        grid.all_simple_antinodes()
+        Ok(grid
+            .all_simple_antinodes()
             .iter()
             .filter(|node| grid.contains(**node))
             .count()
-            .to_string()
+            .into())
     }
 }
@@ -1,16 +1,18 @@
 use crate::AntennaGrid;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle08b {}
 
 impl PuzzlePart for Puzzle08b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Count the number of 'general antinodes' formed by pairs of antennae within a grid"
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         let grid = AntennaGrid::parse(input);
 
-        grid.all_general_antinodes().len().to_string()
+        Ok(grid.all_general_antinodes().len().into())
     }
 }
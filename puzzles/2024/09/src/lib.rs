@@ -0,0 +1,41 @@
+use common::puzzle::{Context, PuzzleError};
+
+pub mod puzzle09a;
+pub mod puzzle09b;
+
+pub struct Puzzle09 {}
+
+impl common::puzzle::Puzzle for Puzzle09 {
+    type PartA = puzzle09a::Puzzle09a;
+    type PartB = puzzle09b::Puzzle09b;
+
+    fn name() -> &'static str {
+        "09"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle09);
+
+/// Parses the disk map on the first line of the input into a list of sizes, where
+/// even entries are sizes of files and odd entries are sizes of spaces.
+fn parse_disk_map(input: &str) -> Result<Vec<usize>, PuzzleError> {
+    let line = input
+        .lines()
+        .next()
+        .filter(|line| !line.is_empty())
+        .context("expected a disk map on the first line")?;
+
+    line.chars()
+        .enumerate()
+        .map(|(idx, c)| {
+            c.to_digit(10)
+                .map(|size| size as usize)
+                .context(format!("expected a digit, not '{c}'"))
+                .map_err(|err| err.at(1, idx + 1))
+        })
+        .collect()
+}
@@ -1,21 +1,18 @@
-use common::puzzle::PuzzlePart;
+use crate::parse_disk_map;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle09a {}
 
 impl PuzzlePart for Puzzle09a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Compute the 'checksum' of a filesystem after compacting it"
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         // even index entries of `input` are sizes of blocks, odd entries are sizes of spaces
-        let input = input
-            .lines()
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as usize)
-            .collect::<Vec<_>>();
+        let input = parse_disk_map(input)?;
 
         // we're going to compute this checksum without ever actually constructing
         // the compacted filesystem. just a bunch of counting and careful bookkeeping.
@@ -92,6 +89,6 @@ impl PuzzlePart for Puzzle09a {
             }
         }
 
-        checksum.to_string()
+        Ok(checksum.into())
     }
 }
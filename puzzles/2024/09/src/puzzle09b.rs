@@ -1,21 +1,18 @@
-use common::puzzle::PuzzlePart;
+use crate::parse_disk_map;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle09b {}
 
 impl PuzzlePart for Puzzle09b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Compute the 'checksum' of a filesystem after compacting it, moving only whole files when possible"
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         // even index entries of `input` are sizes of blocks, odd entries are sizes of spaces
-        let input = input
-            .lines()
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as usize)
-            .collect::<Vec<_>>();
+        let input = parse_disk_map(input)?;
 
         // again, we're gonna do this without actually constructing the compacted filesystem. we ride!
         let mut checksum: usize = 0;
@@ -94,6 +91,6 @@ impl PuzzlePart for Puzzle09b {
             cursor += 1;
         }
 
-        checksum.to_string()
+        Ok(checksum.into())
     }
 }
@@ -0,0 +1,64 @@
+use common::{
+    grid::Grid,
+    grid_def,
+    point::Point,
+    puzzle::{Context, PuzzleError},
+    search,
+};
+
+pub mod puzzle10a;
+pub mod puzzle10b;
+
+pub struct Puzzle10 {}
+
+impl common::puzzle::Puzzle for Puzzle10 {
+    type PartA = puzzle10a::Puzzle10a;
+    type PartB = puzzle10b::Puzzle10b;
+
+    fn name() -> &'static str {
+        "10"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle10);
+
+grid_def!(TrailGrid, u64);
+
+impl TrailGrid {
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        let grid = Grid::try_parse_tiles(input, |c| {
+            c.to_digit(10)
+                .map(u64::from)
+                .context(format!("expected a height from 0 to 9, not '{c}'"))
+        })?;
+        Ok(Self(grid))
+    }
+
+    pub fn trailhead_count(&self, distinct: bool) -> u64 {
+        self.positions_of(&0)
+            .map(|start| {
+                // every trail climbs one step at a time, so they're all shortest paths
+                let trails = search::bfs_all(start, |&pt| self.uphill(pt), |_| false);
+                let peaks = trails.distances.keys().filter(|pt| self[**pt] == 9);
+
+                if distinct {
+                    peaks.map(|pt| trails.count_shortest_paths(pt)).sum()
+                } else {
+                    peaks.count() as u64
+                }
+            })
+            .sum()
+    }
+
+    /// The neighbors of `pt` that are exactly one higher.
+    fn uphill(&self, pt: Point) -> impl Iterator<Item = Point> + '_ {
+        let val = self[pt];
+        self.neighbors4(pt)
+            .filter(move |(_, v)| **v == val + 1)
+            .map(|(neighbor, _)| neighbor)
+    }
+}
@@ -0,0 +1,17 @@
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+use crate::TrailGrid;
+
+pub struct Puzzle10a {}
+
+impl PuzzlePart for Puzzle10a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Compute the sum of the 'scores' of trailheads in a map."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(TrailGrid::parse(input)?.trailhead_count(false).into())
+    }
+}
@@ -0,0 +1,17 @@
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+use crate::TrailGrid;
+
+pub struct Puzzle10b {}
+
+impl PuzzlePart for Puzzle10b {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Compute the sum of the 'ratings' of trailheads in a map."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(TrailGrid::parse(input)?.trailhead_count(true).into())
+    }
+}
@@ -1,17 +1,36 @@
+use common::puzzle::{Context, PuzzleError};
 use std::collections::HashMap;
 
 pub mod puzzle11a;
 pub mod puzzle11b;
 
-pub fn parse(input: &str) -> Vec<u64> {
-    input
+pub struct Puzzle11 {}
+
+impl common::puzzle::Puzzle for Puzzle11 {
+    type PartA = puzzle11a::Puzzle11a;
+    type PartB = puzzle11b::Puzzle11b;
+
+    fn name() -> &'static str {
+        "11"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle11);
+
+pub fn parse(input: &str) -> Result<Vec<u64>, PuzzleError> {
+    let nums = input
         .lines()
         .next()
-        .unwrap()
+        .context("the input is empty")?
         .split(' ')
         .map(str::parse)
-        .map(Result::unwrap)
-        .collect()
+        .collect::<Result<_, _>>();
+
+    nums.map_err(|err| PuzzleError::from(err).at_line(1))
 }
 
 /// start from one number and generate descendents as follows (whichever applies first)
@@ -50,7 +69,7 @@ fn count_descs_memo(num: u64, steps: u64, memo: &mut HashMap<(u64, u64), u64>) -
 /// digits. otherwise returns none
 fn split_digits(num: u64) -> Option<(u64, u64)> {
     let s = num.to_string();
-    if s.len() % 2 != 0 {
+    if !s.len().is_multiple_of(2) {
         return None;
     }
 
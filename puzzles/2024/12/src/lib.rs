@@ -4,6 +4,23 @@ use std::collections::HashSet;
 pub mod puzzle12a;
 pub mod puzzle12b;
 
+pub struct Puzzle12 {}
+
+impl common::puzzle::Puzzle for Puzzle12 {
+    type PartA = puzzle12a::Puzzle12a;
+    type PartB = puzzle12b::Puzzle12b;
+
+    fn name() -> &'static str {
+        "12"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle12);
+
 grid_def!(GardenGrid, char);
 
 impl GardenGrid {
@@ -15,19 +32,15 @@ impl GardenGrid {
         let mut visited: HashSet<Point> = HashSet::new();
         let mut regions = vec![];
 
-        for (row_idx, row) in self.rows().enumerate() {
-            for (col_idx, char) in row.enumerate() {
-                let pt = (row_idx as i64, col_idx as i64).into();
-
-                if visited.contains(&pt) {
-                    continue;
-                }
+        for (pt, &char) in self.enumerate() {
+            if visited.contains(&pt) {
+                continue;
+            }
 
-                let mut points = vec![];
-                self.compute_region_from(&pt, char, &mut points, &mut visited);
+            let mut points = vec![];
+            self.compute_region_from(&pt, char, &mut points, &mut visited);
 
-                regions.push(Region { char, points });
-            }
+            regions.push(Region { char, points });
         }
 
         regions
@@ -46,12 +59,8 @@ impl GardenGrid {
         // check every direction from this point;
         // if the neighboring point matches the character, add to the set
         // and recurse on that point
-        for dir in Dir::all() {
-            let neighbor = pt + dir.step();
-            if self
-                .at(neighbor)
-                .is_some_and(|c| *c == char && !visited.contains(&neighbor))
-            {
+        for (neighbor, &c) in self.neighbors4(*pt) {
+            if c == char && !visited.contains(&neighbor) {
                 self.compute_region_from(&neighbor, char, rgn_pts, visited);
             }
         }
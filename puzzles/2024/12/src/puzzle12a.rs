@@ -1,19 +1,21 @@
 use crate::GardenGrid;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle12a {}
 
 impl PuzzlePart for Puzzle12a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Find the sum of the 'prices' (area * perimeter) of a bunch of regions of a grid."
     }
 
-    fn solve(input: &str) -> String {
-        GardenGrid::parse(input)
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(GardenGrid::parse(input)
             .regions()
             .iter()
             .map(|r| r.area() * r.perimeter())
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 }
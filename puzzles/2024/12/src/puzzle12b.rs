@@ -1,19 +1,21 @@
 use crate::GardenGrid;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle12b {}
 
 impl PuzzlePart for Puzzle12b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Find the sum of the 'prices' (area * number of sides) of a bunch of regions of a grid."
     }
 
-    fn solve(input: &str) -> String {
-        GardenGrid::parse(input)
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(GardenGrid::parse(input)
             .regions()
             .iter()
             .map(|r| r.area() * r.num_sides())
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 }
@@ -0,0 +1,102 @@
+use common::puzzle::{Context, PuzzleError};
+
+pub mod puzzle13a;
+pub mod puzzle13b;
+
+pub struct Puzzle13 {}
+
+impl common::puzzle::Puzzle for Puzzle13 {
+    type PartA = puzzle13a::Puzzle13a;
+    type PartB = puzzle13b::Puzzle13b;
+
+    fn name() -> &'static str {
+        "13"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle13);
+
+const EMBIGGEN_FACTOR: i64 = 10_000_000_000_000;
+
+#[derive(Debug)]
+struct ClawMachine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl ClawMachine {
+    /// Parses all the claw machines in the input, which are separated by blank lines.
+    pub fn parse_all(input: &str) -> Result<Vec<Self>, PuzzleError> {
+        input
+            .split("\n\n")
+            .map(str::trim)
+            .map(|block| Self::parse(block).map_err(|err| err.at_fragment(input, block)))
+            .collect()
+    }
+
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        let rest = input
+            .strip_prefix("Button A: X+")
+            .context("expected \"Button A: X+\"")?;
+        let (ax, rest) = rest.split_once(", Y+").context("expected \", Y+\"")?;
+        let (ay, rest) = rest.split_once("\n").context("missing button B")?;
+
+        let rest = rest
+            .strip_prefix("Button B: X+")
+            .context("expected \"Button B: X+\"")?;
+        let (bx, rest) = rest.split_once(", Y+").context("expected \", Y+\"")?;
+        let (by, rest) = rest.split_once("\n").context("missing prize")?;
+
+        let rest = rest
+            .strip_prefix("Prize: X=")
+            .context("expected \"Prize: X=\"")?;
+        let (px, py) = rest.split_once(", Y=").context("expected \", Y=\"")?;
+
+        Ok(Self {
+            button_a: (ax.parse()?, ay.parse()?),
+            button_b: (bx.parse()?, by.parse()?),
+            prize: (px.parse()?, py.parse()?),
+        })
+    }
+
+    // we're looking for the values of a and b that solve this linear system:
+    //   | ax bx | * | a | = | px |
+    //   | ay by |   | b |   | py |
+    // if D = ax * by - ay * bx, then this has a solution that looks like this:
+    //   | a | = (1/D) |  by -bx | * | px |
+    //   | b |         | -ay  ax |   | py |
+    // expand that product to find a and b:
+    //   a = (1/D) ( by*px - bx*py )
+    //   b = (1/D) ( -ay*px + ax*py )
+    pub fn solve(&self) -> Option<(i64, i64)> {
+        let (ax, ay) = self.button_a;
+        let (bx, by) = self.button_b;
+        let (px, py) = self.prize;
+
+        let det = ax * by - ay * bx;
+
+        let a = by * px - bx * py;
+        if a % det != 0 {
+            return None;
+        }
+        let a = a / det;
+
+        let b = -ay * px + ax * py;
+        if b % det != 0 {
+            return None;
+        }
+        let b = b / det;
+
+        Some((a, b))
+    }
+
+    pub fn embiggen(&mut self) {
+        let (px, py) = self.prize;
+        self.prize = (EMBIGGEN_FACTOR + px, EMBIGGEN_FACTOR + py);
+    }
+}
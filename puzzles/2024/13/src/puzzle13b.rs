@@ -1,20 +1,19 @@
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 use crate::ClawMachine;
 
 pub struct Puzzle13b {}
 
 impl PuzzlePart for Puzzle13b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Find the fewest number of tokens needed to win prizes in some BIG claw machines."
     }
 
-    fn solve(input: &str) -> String {
-        input
-            .split("\n\n")
-            .map(str::trim)
-            .map(ClawMachine::parse)
-            .map(Option::unwrap)
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(ClawMachine::parse_all(input)?
+            .into_iter()
             .map(|mut m| {
                 m.embiggen();
                 m
@@ -23,6 +22,6 @@ impl PuzzlePart for Puzzle13b {
             // button A costs 3, button B costs 1
             .map(|(a, b)| 3 * a + b)
             .sum::<i64>()
-            .to_string()
+            .into())
     }
 }
@@ -1,8 +1,66 @@
-use common::point::Point;
+use common::{
+    point::Point,
+    puzzle::{Context, PuzzleError},
+};
 
 pub mod puzzle14a;
 pub mod puzzle14b;
 
+pub struct Puzzle14 {}
+
+impl common::puzzle::Puzzle for Puzzle14 {
+    type PartA = puzzle14a::Puzzle14a;
+    type PartB = puzzle14b::Puzzle14b;
+
+    fn name() -> &'static str {
+        "14"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle14, b: ignore = "part B has no example (it looks for a picture in the real input)");
+
+common::puzzle_params! {
+    pub struct Params14 {
+        /// The width of the room the robots move around in
+        width: i64 = (example: 11, input: 101),
+
+        /// The height of the room the robots move around in
+        height: i64 = (example: 7, input: 103),
+    }
+}
+
+impl Params14 {
+    fn size(&self) -> (i64, i64) {
+        (self.width, self.height)
+    }
+}
+
+/// Parses one robot per line of the input.
+fn parse_robots(input: &str) -> Result<Vec<RayRobot>, PuzzleError> {
+    // inputs used to start with the size of the room, which is now a parameter
+    let first_line = input.lines().next().unwrap_or_default();
+    if first_line
+        .split_once(',')
+        .is_some_and(|(w, h)| w.parse::<i64>().is_ok() && h.parse::<i64>().is_ok())
+    {
+        return Err(PuzzleError::new(format!(
+            "\"{first_line}\" looks like a room size, which inputs don't start with anymore \
+            (remove this line, and pass --param width=<w> --param height=<h> if it isn't the default)"
+        ))
+        .at_line(1));
+    }
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| RayRobot::parse(line).map_err(|err| err.at_line(idx + 1)))
+        .collect()
+}
+
 #[derive(Debug, PartialEq, Eq)]
 struct RayRobot {
     start: Point,
@@ -15,15 +73,15 @@ impl RayRobot {
     /// input looks like
     ///   p=x,y v=a,b
     /// where x,y,a,b are ints and x,y are nonnegative
-    pub fn parse(line: &str) -> Option<Self> {
-        let rest = line.strip_prefix("p=")?;
-        let (x, rest) = rest.split_once(',')?;
-        let (y, rest) = rest.split_once(" v=")?;
-        let (a, b) = rest.split_once(',')?;
-
-        Some(Self {
-            start: (x.parse::<i64>().ok()?, y.parse::<i64>().ok()?).into(),
-            vel: (a.parse::<i64>().ok()?, b.parse::<i64>().ok()?).into(),
+    pub fn parse(line: &str) -> Result<Self, PuzzleError> {
+        let rest = line.strip_prefix("p=").context("expected \"p=\"")?;
+        let (x, rest) = rest.split_once(',').context("expected \",\"")?;
+        let (y, rest) = rest.split_once(" v=").context("expected \" v=\"")?;
+        let (a, b) = rest.split_once(',').context("expected \",\"")?;
+
+        Ok(Self {
+            start: (x.parse::<i64>()?, y.parse::<i64>()?).into(),
+            vel: (a.parse::<i64>()?, b.parse::<i64>()?).into(),
         })
     }
 
@@ -40,6 +98,14 @@ impl RayRobot {
 mod ray_robot_tests {
     use super::*;
 
+    #[test]
+    fn should_reject_old_room_size_line() {
+        let err = parse_robots("11,7\np=0,4 v=3,-3\n").unwrap_err();
+
+        assert_eq!(err.line(), Some(1));
+        assert!(err.message().contains("looks like a room size"));
+    }
+
     #[test]
     fn should_parse() {
         let line = "p=0,4 v=3,-3";
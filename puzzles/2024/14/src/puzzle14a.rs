@@ -1,27 +1,21 @@
-use crate::{ComputeQuadrant, Quadrant, RayRobot};
-use common::{point::Point, puzzle::PuzzlePart};
+use crate::{parse_robots, ComputeQuadrant, Params14, Quadrant};
+use common::{
+    point::Point,
+    puzzle::{Answer, PuzzleError, PuzzlePart},
+};
 
 pub struct Puzzle14a {}
 
 impl PuzzlePart for Puzzle14a {
+    type Params = Params14;
+
     fn description() -> &'static str {
         "Find the configuration of a bunch of robots after moving in lines over a torus for 100 seconds."
     }
 
-    fn solve(input: &str) -> String {
-        let mut lines = input.lines();
-
-        // the first line of input contains the size
-        let size = lines
-            .next()
-            .and_then(|line| line.split_once(','))
-            .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
-            .unwrap();
-
-        let robots = lines
-            .map(RayRobot::parse)
-            .map(Option::unwrap)
-            .collect::<Vec<_>>();
+    fn solve(input: &str, params: &Params14) -> Result<Answer, PuzzleError> {
+        let size = params.size();
+        let robots = parse_robots(input)?;
 
         let destinations = robots
             .iter()
@@ -43,6 +37,6 @@ impl PuzzlePart for Puzzle14a {
                 }
             });
 
-        (i * ii * iii * iv).to_string()
+        Ok((i * ii * iii * iv).into())
     }
 }
@@ -1,7 +1,10 @@
 use std::path::Path;
 
-use crate::RayRobot;
-use common::{point::Point, puzzle::PuzzlePart};
+use crate::{parse_robots, Params14, RayRobot};
+use common::{
+    point::Point,
+    puzzle::{Answer, Context, PuzzleError, PuzzlePart},
+};
 use image::{ImageBuffer, Rgb};
 
 pub struct Puzzle14b {}
@@ -9,24 +12,15 @@ pub struct Puzzle14b {}
 const ITERS: usize = 100;
 
 impl PuzzlePart for Puzzle14b {
+    type Params = Params14;
+
     fn description() -> &'static str {
         "Find the first step of the robots movement in which their arrangement looks like a christmas tree."
     }
 
-    fn solve(input: &str) -> String {
-        let mut lines = input.lines();
-
-        // the first line of input contains the size
-        let size: (i64, i64) = lines
-            .next()
-            .and_then(|line| line.split_once(','))
-            .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
-            .unwrap();
-
-        let mut robots = lines
-            .map(RayRobot::parse)
-            .map(Option::unwrap)
-            .collect::<Vec<_>>();
+    fn solve(input: &str, params: &Params14) -> Result<Answer, PuzzleError> {
+        let size = params.size();
+        let mut robots = parse_robots(input)?;
 
         let advance_robots = |robots: &mut Vec<RayRobot>, steps: usize| {
             for r in robots {
@@ -56,7 +50,7 @@ impl PuzzlePart for Puzzle14b {
                 size.1 as u32,
                 image::ColorType::Rgb8,
             )
-            .unwrap();
+            .context("could not save image")?;
 
             // update robot positions
             advance_robots(&mut robots, skip);
@@ -77,6 +71,6 @@ impl PuzzlePart for Puzzle14b {
         // - find the smallest positive integer that can be expressed as 12 + 103*n and 69 + 101*m for some
         //   integers n and m
         // - that number is 8149, which is the first image with a tree :)
-        String::from("8149")
+        Ok(8149.into())
     }
 }
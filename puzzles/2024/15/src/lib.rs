@@ -1,8 +1,31 @@
-use common::{dir::Dir, grid::Grid, grid_def, point::Point};
+use common::{
+    dir::Dir,
+    grid::Grid,
+    grid_def,
+    point::Point,
+    puzzle::{Context, PuzzleError},
+};
 
 pub mod puzzle15a;
 pub mod puzzle15b;
 
+pub struct Puzzle15 {}
+
+impl common::puzzle::Puzzle for Puzzle15 {
+    type PartA = puzzle15a::Puzzle15a;
+    type PartB = puzzle15b::Puzzle15b;
+
+    fn name() -> &'static str {
+        "15"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle15);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tile {
     Wall,
@@ -13,13 +36,18 @@ pub enum Tile {
     Nothing,
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = PuzzleError;
+
+    fn try_from(c: char) -> Result<Self, PuzzleError> {
         match c {
-            '#' => Self::Wall,
-            '@' => Self::Robot,
-            'O' => Self::SmallBox,
-            _ => Self::Nothing,
+            '#' => Ok(Self::Wall),
+            '@' => Ok(Self::Robot),
+            'O' => Ok(Self::SmallBox),
+            '.' => Ok(Self::Nothing),
+            _ => Err(PuzzleError::new(format!(
+                "expected '#', '@', 'O' or '.', not '{c}'"
+            ))),
         }
     }
 }
@@ -50,12 +78,14 @@ impl Tile {
 grid_def!(WarehouseGrid, Tile);
 
 impl WarehouseGrid {
-    pub fn parse(input: &str) -> Self {
-        Self(Grid::parse(input))
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        Ok(Self(Grid::try_parse(input)?))
     }
 
-    pub fn move_robot(&mut self, instructions: Instructions) {
-        let mut robot_pos = self.find_pt(|t| t == Tile::Robot).unwrap();
+    pub fn move_robot(&mut self, instructions: Instructions) -> Result<(), PuzzleError> {
+        let mut robot_pos = self
+            .find_pt(|t| t == Tile::Robot)
+            .context("expected a robot ('@') somewhere in the map")?;
 
         // println!("start at {robot_pos:?}");
         // println!("{}", self.0);
@@ -73,6 +103,7 @@ impl WarehouseGrid {
             }
             // println!("now at {robot_pos:?}\n{}", self.0);
         }
+        Ok(())
     }
 
     /// pushes the box at `pt` in the direction `dir`, if possible,
@@ -116,7 +147,8 @@ impl WarehouseGrid {
             let (left_pt, right_pt): (Point, Point) = match self.at(pt) {
                 Some(Tile::BigBoxLeft) => (pt, (pt.row, pt.col + 1).into()),
                 Some(Tile::BigBoxRight) => ((pt.row, pt.col - 1).into(), pt),
-                _ => unreachable!(),
+                // off the edge of the map, which nothing can be pushed past
+                _ => return false,
             };
 
             // if we're moving north or south, check that the spaces above/below both blocks
@@ -169,38 +201,36 @@ impl WarehouseGrid {
         }
     }
 
-    pub fn embiggen(&mut self) {
+    /// Doubles the width of the map, turning each small box into a big one.
+    /// Fails if the map has already been doubled.
+    pub fn embiggen(&mut self) -> Result<(), PuzzleError> {
         let big_tiles = self
             .rows()
             .map(|row| {
-                row.flat_map(|tile| match tile {
-                    Tile::Wall => [Tile::Wall; 2],
-                    Tile::Robot => [Tile::Robot, Tile::Nothing],
-                    Tile::SmallBox => [Tile::BigBoxLeft, Tile::BigBoxRight],
-                    Tile::Nothing => [Tile::Nothing; 2],
-                    _ => unreachable!(),
+                row.map(|tile| match tile {
+                    Tile::Wall => Ok([Tile::Wall; 2]),
+                    Tile::Robot => Ok([Tile::Robot, Tile::Nothing]),
+                    Tile::SmallBox => Ok([Tile::BigBoxLeft, Tile::BigBoxRight]),
+                    Tile::Nothing => Ok([Tile::Nothing; 2]),
+                    Tile::BigBoxLeft | Tile::BigBoxRight => {
+                        Err(PuzzleError::new("the map already has big boxes"))
+                    }
                 })
-                .collect()
+                .collect::<Result<Vec<_>, _>>()
+                .map(|pairs| pairs.into_iter().flatten().collect())
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        self.0 .0 = big_tiles;
+        self.0 = Grid::from_rows(big_tiles)?;
+        Ok(())
     }
 
     /// the "gps coordinate" of a box is 100 times its distance from the top
     /// edge of the map plus its distance from the left edge of the map
     pub fn box_gps_coord_sum(&self) -> usize {
-        let mut sum = 0;
-
-        for (row_idx, row) in self.rows().enumerate() {
-            for (col_idx, tile) in row.enumerate() {
-                if tile == Tile::SmallBox || tile == Tile::BigBoxLeft {
-                    sum += 100 * row_idx + col_idx;
-                }
-            }
-        }
-
-        sum
+        self.find_all(|tile| *tile == Tile::SmallBox || *tile == Tile::BigBoxLeft)
+            .map(|pt| 100 * pt.row as usize + pt.col as usize)
+            .sum()
     }
 }
 
@@ -208,18 +238,45 @@ impl WarehouseGrid {
 pub struct Instructions(Vec<Dir>);
 
 impl Instructions {
-    pub fn parse(input: &str) -> Self {
+    /// Parses the moves, which can be split over many lines.
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
         let dirs = input
-            .chars()
-            .filter_map(|c| match c {
-                '^' => Some(Dir::North),
-                'v' => Some(Dir::South),
-                '>' => Some(Dir::East),
-                '<' => Some(Dir::West),
-                _ => None,
+            .lines()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars().enumerate().map(move |(col, c)| match c {
+                    '^' => Ok(Dir::North),
+                    'v' => Ok(Dir::South),
+                    '>' => Ok(Dir::East),
+                    '<' => Ok(Dir::West),
+                    _ => Err(PuzzleError::new(format!(
+                        "expected a move ('^', 'v', '>' or '<'), not '{c}'"
+                    ))
+                    .at(row + 1, col + 1)),
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Self(dirs)
+        Ok(Self(dirs))
     }
 }
+
+/// Parses the map of the warehouse and the robot's moves, which follow it after
+/// a blank line.
+pub fn parse_input(input: &str) -> Result<(WarehouseGrid, Instructions), PuzzleError> {
+    let (grid_str, instructions_str) = input
+        .split_once("\n\n")
+        .context("expected a blank line between the map and the moves")?;
+
+    let grid = WarehouseGrid::parse(grid_str)?;
+
+    // the moves' lines are counted from the start of the moves, not the input
+    let skipped_lines = grid_str.lines().count() + 1;
+    let instructions =
+        Instructions::parse(instructions_str).map_err(|err| match (err.line(), err.column()) {
+            (Some(line), Some(column)) => err.at(line + skipped_lines, column),
+            _ => err,
+        })?;
+
+    Ok((grid, instructions))
+}
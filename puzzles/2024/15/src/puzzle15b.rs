@@ -0,0 +1,20 @@
+use crate::parse_input;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle15b {}
+
+impl PuzzlePart for Puzzle15b {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Find the coordinates of some boxes after they are pushed around by a robot, but on a horizontally-scaled map."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (mut grid, instructions) = parse_input(input)?;
+
+        grid.embiggen()?;
+        grid.move_robot(instructions)?;
+        Ok(grid.box_gps_coord_sum().into())
+    }
+}
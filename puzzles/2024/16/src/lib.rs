@@ -0,0 +1,170 @@
+use common::{
+    dir::Dir,
+    grid::Grid,
+    grid_def,
+    point::Point,
+    puzzle::{Context, PuzzleError},
+    search::{self, SearchResult},
+};
+use std::collections::HashSet;
+
+pub mod puzzle16a;
+pub mod puzzle16b;
+
+pub struct Puzzle16 {}
+
+impl common::puzzle::Puzzle for Puzzle16 {
+    type PartA = puzzle16a::Puzzle16a;
+    type PartB = puzzle16b::Puzzle16b;
+
+    fn name() -> &'static str {
+        "16"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle16);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Nothing,
+    Start,
+    End,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = PuzzleError;
+
+    fn try_from(c: char) -> Result<Self, PuzzleError> {
+        match c {
+            '#' => Ok(Self::Wall),
+            '.' => Ok(Self::Nothing),
+            'S' => Ok(Self::Start),
+            'E' => Ok(Self::End),
+            _ => Err(PuzzleError::new(format!(
+                "expected '#', '.', 'S' or 'E', not '{c}'"
+            ))),
+        }
+    }
+}
+
+grid_def!(Maze, Tile);
+
+impl Maze {
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        Ok(Self(Grid::try_parse(input)?))
+    }
+
+    pub fn shortest_path_score(&self) -> Result<u64, PuzzleError> {
+        let end_pt = self.end_pt()?;
+        self.search(|(pt, _)| *pt == end_pt)?
+            .goal_distance()
+            .context("the end is unreachable")
+    }
+
+    fn start_pt(&self) -> Result<Point, PuzzleError> {
+        self.find_pt(|t| t == Tile::Start)
+            .context("no start tile ('S') in the maze")
+    }
+
+    fn end_pt(&self) -> Result<Point, PuzzleError> {
+        self.find_pt(|t| t == Tile::End)
+            .context("no end tile ('E') in the maze")
+    }
+
+    /// Finds the lowest scores for reaching (point, direction) states from the
+    /// start, going east, until reaching a state that `is_goal` accepts.
+    fn search(
+        &self,
+        is_goal: impl FnMut(&(Point, Dir)) -> bool,
+    ) -> Result<SearchResult<(Point, Dir)>, PuzzleError> {
+        let start_pt = self.start_pt()?;
+
+        Ok(search::dijkstra(
+            (start_pt, Dir::East),
+            |&(pt, dir)| self.moves(pt, dir),
+            is_goal,
+        ))
+    }
+
+    /// Like `search`, but explores everywhere and records every best path.
+    fn search_all(&self) -> Result<SearchResult<(Point, Dir)>, PuzzleError> {
+        let start_pt = self.start_pt()?;
+
+        Ok(search::dijkstra_all(
+            (start_pt, Dir::East),
+            |&(pt, dir)| self.moves(pt, dir),
+            |_| false,
+        ))
+    }
+
+    // we can possibly:
+    // - move forward
+    // - turn left then move forward
+    // - turn right then move forward
+    fn moves(&self, pt: Point, dir: Dir) -> impl Iterator<Item = ((Point, Dir), u64)> + '_ {
+        [(dir, 1), (dir.turn_right(), 1001), (dir.turn_left(), 1001)]
+            .into_iter()
+            .map(move |(dir, cost)| ((pt + dir.step(), dir), cost))
+            .filter(|((next_pt, _), _)| self.at(*next_pt).is_some_and(|t| *t != Tile::Wall))
+    }
+
+    pub fn shortest_path_tiles(&self) -> Result<usize, PuzzleError> {
+        let end_pt = self.end_pt()?;
+        let result = self.search_all()?;
+
+        // we might reach the end facing different ways, so any of those that
+        // ties for the lowest score finishes a best path
+        let ends = result
+            .distances
+            .iter()
+            .filter(|((pt, _), _)| *pt == end_pt)
+            .collect::<Vec<_>>();
+        let score = ends
+            .iter()
+            .map(|(_, score)| **score)
+            .min()
+            .context("the end is unreachable")?;
+
+        Ok(ends
+            .into_iter()
+            .filter(|(_, s)| **s == score)
+            .flat_map(|(end, _)| result.on_shortest_paths(end))
+            .map(|(pt, _)| pt)
+            .collect::<HashSet<_>>()
+            .len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_fail_if_end_is_unreachable() {
+        let maze = Maze::parse("#####\n#S#E#\n#####").unwrap();
+
+        let err = maze.shortest_path_score().unwrap_err();
+        assert_eq!(err.message(), "the end is unreachable");
+    }
+
+    #[test]
+    fn should_fail_to_find_tiles_if_end_is_unreachable() {
+        let maze = Maze::parse("#####\n#S#E#\n#####").unwrap();
+
+        let err = maze.shortest_path_tiles().unwrap_err();
+        assert_eq!(err.message(), "the end is unreachable");
+    }
+
+    #[test]
+    fn should_fail_to_find_tiles_without_start() {
+        let maze = Maze::parse("#####\n#..E#\n#####").unwrap();
+
+        let err = maze.shortest_path_tiles().unwrap_err();
+        assert_eq!(err.message(), "no start tile ('S') in the maze");
+    }
+}
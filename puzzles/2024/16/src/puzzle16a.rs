@@ -0,0 +1,16 @@
+use crate::Maze;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle16a {}
+
+impl PuzzlePart for Puzzle16a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Find the lowest 'score' of a path through a maze."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(Maze::parse(input)?.shortest_path_score()?.into())
+    }
+}
@@ -1,14 +1,16 @@
 use crate::Maze;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle16b {}
 
 impl PuzzlePart for Puzzle16b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Find the number of locations that are in one of the lowest-scoring paths."
     }
 
-    fn solve(input: &str) -> String {
-        Maze::parse(input).shortest_path_tiles().to_string()
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(Maze::parse(input)?.shortest_path_tiles()?.into())
     }
 }
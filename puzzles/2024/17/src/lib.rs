@@ -1,6 +1,25 @@
+use common::puzzle::{Context, PuzzleError};
+
 pub mod puzzle17a;
 pub mod puzzle17b;
 
+pub struct Puzzle17 {}
+
+impl common::puzzle::Puzzle for Puzzle17 {
+    type PartA = puzzle17a::Puzzle17a;
+    type PartB = puzzle17b::Puzzle17b;
+
+    fn name() -> &'static str {
+        "17"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle17, b: ignore = "part B only solves programs shaped like the real input");
+
 const DEBUG: bool = false;
 
 fn debug(s: &str) {
@@ -71,18 +90,20 @@ enum Instruction {
     Cdv,
 }
 
-impl From<u8> for Instruction {
-    fn from(v: u8) -> Self {
+impl TryFrom<u8> for Instruction {
+    type Error = PuzzleError;
+
+    fn try_from(v: u8) -> Result<Self, PuzzleError> {
         match v {
-            0 => Self::Adv,
-            1 => Self::Bxl,
-            2 => Self::Bst,
-            3 => Self::Jnz,
-            4 => Self::Bxc,
-            5 => Self::Out,
-            6 => Self::Bdv,
-            7 => Self::Cdv,
-            _ => unreachable!(),
+            0 => Ok(Self::Adv),
+            1 => Ok(Self::Bxl),
+            2 => Ok(Self::Bst),
+            3 => Ok(Self::Jnz),
+            4 => Ok(Self::Bxc),
+            5 => Ok(Self::Out),
+            6 => Ok(Self::Bdv),
+            7 => Ok(Self::Cdv),
+            _ => Err(PuzzleError::new(format!("invalid opcode {v}"))),
         }
     }
 }
@@ -94,21 +115,38 @@ enum Operand {
 }
 
 impl Computer {
-    pub fn parse(input: &str) -> Option<Self> {
-        let mut lines = input.lines();
-
-        let reg_a = lines.next()?.strip_prefix("Register A: ")?.parse().ok()?;
-        let reg_b = lines.next()?.strip_prefix("Register B: ")?.parse().ok()?;
-        let reg_c = lines.next()?.strip_prefix("Register C: ")?.parse().ok()?;
-
-        let program = lines
-            .nth(1)?
-            .strip_prefix("Program: ")?
-            .split(',')
-            .map(|s| s.parse().unwrap())
-            .collect();
-
-        Some(Self {
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        let lines = input.lines().collect::<Vec<_>>();
+
+        let register = |idx: usize, name: &str| -> Result<i64, PuzzleError> {
+            let prefix = format!("Register {name}: ");
+            lines
+                .get(idx)
+                .and_then(|line| line.strip_prefix(&prefix))
+                .context(format!("expected \"{prefix}<value>\""))?
+                .parse()
+                .map_err(|err| PuzzleError::from(err).at_line(idx + 1))
+        };
+
+        let reg_a = register(0, "A")?;
+        let reg_b = register(1, "B")?;
+        let reg_c = register(2, "C")?;
+
+        let program_line = lines.get(4).context("missing program")?;
+        let program =
+            program_line
+                .strip_prefix("Program: ")
+                .context("expected \"Program: <values>\"")
+                .map_err(|err| err.at_line(5))?
+                .split(',')
+                .map(|s| match s.parse() {
+                    Ok(n) if n <= 7 => Ok(n),
+                    _ => Err(PuzzleError::new(format!("invalid 3-bit value '{s}'"))
+                        .at_fragment(input, s)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
             reg_a,
             reg_b,
             reg_c,
@@ -118,56 +156,63 @@ impl Computer {
         })
     }
 
-    fn op_value(&self, op: Operand) -> i64 {
+    fn op_value(&self, op: Operand) -> Result<i64, PuzzleError> {
         match op {
-            Operand::Literal(n) => n as i64,
-            Operand::Combo(n) if (0..=3).contains(&n) => n as i64,
-            Operand::Combo(4) => self.reg_a,
-            Operand::Combo(5) => self.reg_b,
-            Operand::Combo(6) => self.reg_c,
-            _ => unreachable!(),
+            Operand::Literal(n) => Ok(n as i64),
+            Operand::Combo(n) if (0..=3).contains(&n) => Ok(n as i64),
+            Operand::Combo(4) => Ok(self.reg_a),
+            Operand::Combo(5) => Ok(self.reg_b),
+            Operand::Combo(6) => Ok(self.reg_c),
+            Operand::Combo(n) => Err(PuzzleError::new(format!(
+                "combo operand {n} (at program value {}) is reserved",
+                self.ptr + 2
+            ))),
         }
     }
 
-    fn run(&mut self) {
+    fn run(&mut self) -> Result<(), PuzzleError> {
         while self.ptr < self.program.len() {
-            let instruction = self.program[self.ptr].into();
-            let input = self.program[self.ptr + 1];
-            self.apply(instruction, input);
+            let instruction = self.program[self.ptr].try_into()?;
+            let input = *self.program.get(self.ptr + 1).context(format!(
+                "the program ends without an operand for its last instruction (at program value {})",
+                self.ptr + 1
+            ))?;
+            self.apply(instruction, input)?;
             if instruction != Instruction::Jnz {
                 self.ptr += 2;
             }
         }
+        Ok(())
     }
 
-    fn apply(&mut self, instruction: Instruction, input: u8) {
+    fn apply(&mut self, instruction: Instruction, input: u8) -> Result<(), PuzzleError> {
         debug(&format!("{:?}", &self));
         debug(&format!("Applying {instruction:?} with input {input}"));
 
         match instruction {
             Instruction::Adv => {
                 let num = self.reg_a;
-                let denom = 2i64.pow(self.op_value(Operand::Combo(input)) as u32);
+                let denom = 2i64.pow(self.op_value(Operand::Combo(input))? as u32);
                 self.reg_a = num / denom;
                 debug(&format!("Set A to {num}/{denom} = {}", self.reg_a));
             }
 
             Instruction::Bxl => {
                 let left = self.reg_b;
-                let right = self.op_value(Operand::Literal(input));
+                let right = self.op_value(Operand::Literal(input))?;
                 self.reg_b = left ^ right;
                 debug(&format!("Set B to {left} ^ {right} = {}", self.reg_b));
             }
 
             Instruction::Bst => {
-                let op_value = self.op_value(Operand::Combo(input));
+                let op_value = self.op_value(Operand::Combo(input))?;
                 self.reg_b = op_value % 8;
                 debug(&format!("Set B to {op_value} %8 = {}", self.reg_b));
             }
 
             Instruction::Jnz => {
                 if self.reg_a != 0 {
-                    self.ptr = self.op_value(Operand::Literal(input)) as usize;
+                    self.ptr = self.op_value(Operand::Literal(input))? as usize;
                     debug(&format!("Jumped to {}", self.ptr));
                 } else {
                     self.ptr += 2;
@@ -182,27 +227,28 @@ impl Computer {
             }
 
             Instruction::Out => {
-                let out = self.op_value(Operand::Combo(input)) % 8;
+                let out = self.op_value(Operand::Combo(input))? % 8;
                 self.output.push(out as u8);
                 debug(&format!("Output {out}"));
             }
 
             Instruction::Bdv => {
                 let num = self.reg_a;
-                let denom = 2i64.pow(self.op_value(Operand::Combo(input)) as u32);
+                let denom = 2i64.pow(self.op_value(Operand::Combo(input))? as u32);
                 self.reg_b = num / denom;
                 debug(&format!("Set B to {num}/{denom} = {}", self.reg_b));
             }
 
             Instruction::Cdv => {
                 let num = self.reg_a;
-                let denom = 2i64.pow(self.op_value(Operand::Combo(input)) as u32);
+                let denom = 2i64.pow(self.op_value(Operand::Combo(input))? as u32);
                 self.reg_c = num / denom;
                 debug(&format!("Set C to {num}/{denom} = {}", self.reg_c));
             }
         }
 
         debug("-------------");
+        Ok(())
     }
 
     /// Runs the specific program given by:
@@ -229,7 +275,7 @@ impl Computer {
             let out = (a_mod_8 ^ 6 ^ (a / 2i64.pow(3 ^ a_mod_8 as u32))) % 8;
 
             // check if this output matches the program at the current index
-            if target[idx] as i64 != out {
+            if target.get(idx).is_none_or(|&value| value as i64 != out) {
                 return false;
             }
             output.push(out as u8);
@@ -243,3 +289,27 @@ impl Computer {
         idx == target.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_locate_parse_errors() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,9,0";
+        let err = Computer::parse(input).unwrap_err();
+
+        assert_eq!(err.line(), Some(5));
+        assert_eq!(err.column(), Some(14));
+        assert_eq!(err.message(), "invalid 3-bit value '9'");
+    }
+
+    #[test]
+    fn should_reject_reserved_combo_operand() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7";
+        let mut computer = Computer::parse(input).unwrap();
+
+        let err = computer.run().unwrap_err();
+        assert!(err.message().contains("combo operand 7"));
+    }
+}
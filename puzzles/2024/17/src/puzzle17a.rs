@@ -0,0 +1,21 @@
+use crate::Computer;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+use itertools::Itertools;
+
+pub struct Puzzle17a {}
+
+impl PuzzlePart for Puzzle17a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Find the output of a 3-bit computer."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let mut computer = Computer::parse(input)?;
+
+        computer.run()?;
+
+        Ok(computer.output.iter().map(u8::to_string).join(",").into())
+    }
+}
@@ -1,9 +1,11 @@
 use crate::Computer;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle17b {}
 
 impl PuzzlePart for Puzzle17b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Find the smallest possible initial value in register A so that the program outputs itself."
     }
@@ -12,10 +14,17 @@ impl PuzzlePart for Puzzle17b {
     /// then, since A is divided by 8 between loops of the program,
     /// check all "preimages" of the found value under division by 8 in order
     /// to find a value of A that leads to a longer suffix. repeat until you win
-    fn solve(input: &str) -> String {
-        let mut computer = Computer::parse(input).unwrap();
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let mut computer = Computer::parse(input)?;
 
         // the program has 16 elements, so we'll start at the last one and work our way forwards
+        if computer.program.len() != 16 {
+            return Err(format!(
+                "expected 16 program values, found {}",
+                computer.program.len()
+            )
+            .into());
+        }
         let start_idx = 15;
         let target = &computer.program[start_idx..];
 
@@ -30,7 +39,7 @@ impl PuzzlePart for Puzzle17b {
 
             if computer.has_output(target) {
                 if let Some(res) = check_preimages(&computer, start_idx - 1, computer.reg_a) {
-                    break res.to_string();
+                    break Ok(res.into());
                 }
             }
         }
@@ -0,0 +1,154 @@
+use common::{
+    grid::Grid,
+    grid_def,
+    point::Point,
+    puzzle::{Context, PuzzleError},
+    search,
+};
+
+pub mod puzzle18a;
+pub mod puzzle18b;
+
+pub struct Puzzle18 {}
+
+impl common::puzzle::Puzzle for Puzzle18 {
+    type PartA = puzzle18a::Puzzle18a;
+    type PartB = puzzle18b::Puzzle18b;
+
+    fn name() -> &'static str {
+        "18"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle18);
+
+common::puzzle_params! {
+    pub struct Params18 {
+        /// The width and height of the (square) memory space
+        size: usize = (example: 7, input: 71),
+
+        /// How many bytes have fallen before we start looking for a path
+        max_bytes: usize = (example: 12, input: 1024),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Byte,
+    Nothing,
+    Visited,
+}
+
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::Byte => '#',
+            Tile::Nothing => '.',
+            Tile::Visited => 'O',
+        }
+    }
+}
+
+grid_def!(ByteGrid, Tile);
+
+/// Parses a "row,col" line, with `line_num` (1-based) used for error messages,
+/// failing if the coordinates are outside a `size` by `size` grid.
+pub fn make_coords(line: &str, line_num: usize, size: usize) -> Result<(i64, i64), PuzzleError> {
+    let (row, col) = line
+        .split_once(',')
+        .context("expected coordinates like \"3,4\"")
+        .and_then(|(rows_str, cols_str)| Ok((rows_str.parse::<i64>()?, cols_str.parse::<i64>()?)))
+        .map_err(|err| err.at_line(line_num))?;
+
+    let in_grid = |n: i64| usize::try_from(n).is_ok_and(|n| n < size);
+    if !in_grid(row) || !in_grid(col) {
+        let mut message = format!("\"{row},{col}\" is outside the {size}x{size} grid");
+
+        // inputs used to start with the size of the grid, which is now a parameter,
+        // and which would be outside the grid if it were a byte
+        if line_num == 1 {
+            message.push_str(
+                "; if it's the grid size, which inputs don't start with anymore, remove it \
+                (and pass --param size=<size> if it isn't the default)",
+            );
+        }
+        return Err(PuzzleError::new(message).at_line(line_num));
+    }
+
+    Ok((row, col))
+}
+
+impl ByteGrid {
+    pub fn parse(input: &str, params: &Params18) -> Result<Self, PuzzleError> {
+        let size = params.size;
+        if size == 0 {
+            return Err("the grid size must be at least 1".into());
+        }
+
+        let coords = input
+            .lines()
+            .take(params.max_bytes)
+            .enumerate()
+            .map(|(idx, line)| make_coords(line, idx + 1, size))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // make a grid
+        let grid = Grid::from_fn(size, size, |pt| {
+            if coords.contains(&(pt.row, pt.col)) {
+                Tile::Byte
+            } else {
+                Tile::Nothing
+            }
+        });
+
+        Ok(Self(grid))
+    }
+
+    pub fn add_byte(&mut self, pt: Point) {
+        self.put(Tile::Byte, pt);
+    }
+
+    /// The fewest steps it takes to get from `start` to `end` without walking into a byte.
+    pub fn least_steps(&self, start: Point, end: Point) -> Option<usize> {
+        let successors = |&pt: &Point| {
+            self.neighbors4(pt)
+                .filter(|(_, t)| **t != Tile::Byte)
+                .map(|(neighbor, _)| neighbor)
+        };
+
+        search::bfs(start, successors, |pt| *pt == end)
+            .goal_distance()
+            .map(|steps| steps as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_reject_coords_outside_grid() {
+        let err = make_coords("3,7", 5, 7).unwrap_err();
+
+        assert_eq!(err.line(), Some(5));
+        assert_eq!(err.message(), "\"3,7\" is outside the 7x7 grid");
+        assert!(make_coords("-1,0", 5, 7).is_err());
+        assert_eq!(make_coords("6,0", 5, 7).unwrap(), (6, 0));
+    }
+
+    #[test]
+    fn should_point_out_old_size_line() {
+        let params = Params18 {
+            size: 7,
+            max_bytes: 12,
+        };
+        let err = ByteGrid::parse("7,7\n0,0", &params).unwrap_err();
+
+        assert_eq!(err.line(), Some(1));
+        assert!(err.message().contains("if it's the grid size"));
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{ByteGrid, Params18, Tile};
+use common::puzzle::{Answer, Context, PuzzleError, PuzzlePart};
+
+pub struct Puzzle18a {}
+
+impl PuzzlePart for Puzzle18a {
+    type Params = Params18;
+
+    fn description() -> &'static str {
+        "Find the minimum number of steps needed to reach from one corner of a maze of fallen bytes to the other."
+    }
+
+    fn solve(input: &str, params: &Params18) -> Result<Answer, PuzzleError> {
+        let grid = ByteGrid::parse(input, params)?;
+
+        let start = (0, 0).into();
+        let end = (grid.height() as i64 - 1, grid.width() as i64 - 1).into();
+        let path = grid
+            .shortest_path(start, end, |t| *t != Tile::Byte)
+            .context("there's no path to the exit")?;
+
+        Ok((path.len() - 1).into())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{make_coords, ByteGrid, Params18, Tile};
+use common::{
+    point::Point,
+    puzzle::{Answer, PuzzleError, PuzzlePart},
+};
+
+pub struct Puzzle18b {}
+
+impl PuzzlePart for Puzzle18b {
+    type Params = Params18;
+
+    fn description() -> &'static str {
+        "Find the coordinates of the first point (after the initial maze) that blocks off the exit."
+    }
+
+    fn solve(input: &str, params: &Params18) -> Result<Answer, PuzzleError> {
+        let mut grid = ByteGrid::parse(input, params)?;
+
+        let remaining_pts = input
+            .lines()
+            .enumerate()
+            .skip(params.max_bytes)
+            .map(|(idx, line)| make_coords(line, idx + 1, params.size).map(Point::from));
+
+        let from = (0, 0).into();
+        let to = (grid.height() as i64 - 1, grid.width() as i64 - 1).into();
+
+        for pt in remaining_pts {
+            let pt = pt?;
+            grid.add_byte(pt);
+
+            if grid.shortest_path(from, to, |t| *t != Tile::Byte).is_none() {
+                return Ok(format!("{},{}", pt.row, pt.col).into());
+            }
+        }
+
+        Err("no byte ever blocks off the exit".into())
+    }
+}
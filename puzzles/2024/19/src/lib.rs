@@ -0,0 +1,39 @@
+use common::puzzle::{Context, PuzzleError};
+
+pub mod puzzle19a;
+pub mod puzzle19b;
+
+pub struct Puzzle19 {}
+
+impl common::puzzle::Puzzle for Puzzle19 {
+    type PartA = puzzle19a::Puzzle19a;
+    type PartB = puzzle19b::Puzzle19b;
+
+    fn name() -> &'static str {
+        "19"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle19);
+
+/// Parses the towel patterns on the first line, and the designs after the blank
+/// line that follows them.
+fn parse_towels(input: &str) -> Result<(Vec<&str>, Vec<&str>), PuzzleError> {
+    let mut lines = input.lines();
+    let patterns = lines
+        .next()
+        .filter(|line| !line.is_empty())
+        .context("expected towel patterns on the first line")?
+        .split(", ")
+        .collect();
+
+    if lines.next().is_some_and(|line| !line.is_empty()) {
+        return Err(PuzzleError::new("expected a blank line after the towel patterns").at_line(2));
+    }
+
+    Ok((patterns, lines.collect()))
+}
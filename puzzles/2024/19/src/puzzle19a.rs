@@ -1,30 +1,26 @@
 use std::collections::HashMap;
 
-use common::puzzle::PuzzlePart;
+use crate::parse_towels;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle19a {}
 
 impl PuzzlePart for Puzzle19a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Count how many stripe designs can be constructed from stripe patterns."
     }
 
-    fn solve(input: &str) -> String {
-        let patterns = input
-            .lines()
-            .next()
-            .unwrap()
-            .split(", ")
-            .collect::<Vec<_>>();
-
-        let designs = input.lines().skip(2).collect::<Vec<_>>();
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (patterns, designs) = parse_towels(input)?;
         let mut history: HashMap<&str, bool> = HashMap::new();
 
-        designs
+        Ok(designs
             .into_iter()
             .filter(|design| can_build(design, &patterns, &mut history))
             .count()
-            .to_string()
+            .into())
     }
 }
 
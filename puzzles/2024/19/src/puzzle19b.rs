@@ -1,30 +1,26 @@
 use std::collections::HashMap;
 
-use common::puzzle::PuzzlePart;
+use crate::parse_towels;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle19b {}
 
 impl PuzzlePart for Puzzle19b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Count the number of ways all of the stripe designs can be constructed from stripe patterns."
     }
 
-    fn solve(input: &str) -> String {
-        let patterns = input
-            .lines()
-            .next()
-            .unwrap()
-            .split(", ")
-            .collect::<Vec<_>>();
-
-        let designs = input.lines().skip(2).collect::<Vec<_>>();
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let (patterns, designs) = parse_towels(input)?;
         let mut history: HashMap<&str, u64> = HashMap::new();
 
-        designs
+        Ok(designs
             .into_iter()
             .map(|design| how_many_ways(design, &patterns, &mut history))
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 }
 
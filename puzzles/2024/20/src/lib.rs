@@ -1,9 +1,26 @@
-use common::{grid::Grid, grid_def, point::Point};
+use common::{grid::Grid, grid_def, point::Point, puzzle::PuzzleError};
 use std::collections::{HashMap, HashSet};
 
 pub mod puzzle20a;
 pub mod puzzle20b;
 
+pub struct Puzzle20 {}
+
+impl common::puzzle::Puzzle for Puzzle20 {
+    type PartA = puzzle20a::Puzzle20a;
+    type PartB = puzzle20b::Puzzle20b;
+
+    fn name() -> &'static str {
+        "20"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle20);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Tile {
     Nothing,
@@ -12,14 +29,18 @@ pub enum Tile {
     End,
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = PuzzleError;
+
+    fn try_from(c: char) -> Result<Self, PuzzleError> {
         match c {
-            '.' => Tile::Nothing,
-            '#' => Tile::Wall,
-            'S' => Tile::Start,
-            'E' => Tile::End,
-            _ => unreachable!(),
+            '.' => Ok(Tile::Nothing),
+            '#' => Ok(Tile::Wall),
+            'S' => Ok(Tile::Start),
+            'E' => Ok(Tile::End),
+            _ => Err(PuzzleError::new(format!(
+                "expected '.', '#', 'S' or 'E', not '{c}'"
+            ))),
         }
     }
 }
@@ -35,8 +56,8 @@ grid_def!(Racetrack, Tile);
 pub type Cheat = (Point, Point, i64);
 
 impl Racetrack {
-    pub fn parse(input: &str) -> Self {
-        Racetrack(Grid::parse(input))
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        Ok(Racetrack(Grid::try_parse(input)?))
     }
 
     /// A "cheat" is two points on the track (ie. not walls)
@@ -72,8 +93,9 @@ impl Racetrack {
         cheats
             .iter()
             .filter_map(|(p1, p2, skipped)| {
-                let i1 = path_indices[p1];
-                let i2 = path_indices[p2];
+                // every space should be on the path, but skip any that aren't
+                let i1 = *path_indices.get(p1)?;
+                let i2 = *path_indices.get(p2)?;
 
                 if i1 > i2 {
                     None
@@ -0,0 +1,41 @@
+use crate::{Racetrack, Tile};
+use common::puzzle::{Answer, Context, PuzzleError, PuzzlePart};
+
+pub struct Puzzle20a {}
+
+common::puzzle_params! {
+    pub struct Params20a {
+        /// Only count cheats that save at least this many steps
+        threshold: usize = (example: 20, input: 100),
+    }
+}
+
+impl PuzzlePart for Puzzle20a {
+    type Params = Params20a;
+
+    fn description() -> &'static str {
+        "Count the number of 2-second 'cheats' that would save at least 100 steps in a shortest path maze."
+    }
+
+    fn solve(input: &str, params: &Params20a) -> Result<Answer, PuzzleError> {
+        let grid = Racetrack::parse(input)?;
+        let start = grid
+            .find_pt(|t| t == Tile::Start)
+            .context("no start in the grid")?;
+        let end = grid
+            .find_pt(|t| t == Tile::End)
+            .context("no end in the grid")?;
+
+        let path = grid
+            .shortest_path(start, end, Tile::is_space)
+            .context("there's no path from start to end")?;
+        let cheats = grid.cheats(&path, 2);
+
+        Ok(grid
+            .savings(&path, cheats)
+            .into_iter()
+            .filter(|s| *s >= params.threshold)
+            .count()
+            .into())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{Racetrack, Tile};
+use common::puzzle::{Answer, Context, PuzzleError, PuzzlePart};
+
+pub struct Puzzle20b {}
+
+common::puzzle_params! {
+    pub struct Params20b {
+        /// Only count cheats that save at least this many steps
+        threshold: usize = (example: 70, input: 100),
+    }
+}
+
+impl PuzzlePart for Puzzle20b {
+    type Params = Params20b;
+
+    fn description() -> &'static str {
+        "Count the number of up-to-20-second 'cheats' that would save at least 100 steps in a shortest path maze."
+    }
+
+    fn solve(input: &str, params: &Params20b) -> Result<Answer, PuzzleError> {
+        let grid = Racetrack::parse(input)?;
+        let start = grid
+            .find_pt(|t| t == Tile::Start)
+            .context("no start in the grid")?;
+        let end = grid
+            .find_pt(|t| t == Tile::End)
+            .context("no end in the grid")?;
+
+        let path = grid
+            .shortest_path(start, end, Tile::is_space)
+            .context("there's no path from start to end")?;
+        let cheats = grid.cheats(&path, 20);
+
+        Ok(grid
+            .savings(&path, cheats)
+            .into_iter()
+            .filter(|s| *s >= params.threshold)
+            .count()
+            .into())
+    }
+}
@@ -1,26 +1,63 @@
 use std::collections::HashMap;
 
-use common::point::Point;
+use common::{
+    point::Point,
+    puzzle::{Context, PuzzleError},
+};
 
 pub mod puzzle21a;
 pub mod puzzle21b;
 
+pub struct Puzzle21 {}
+
+impl common::puzzle::Puzzle for Puzzle21 {
+    type PartA = puzzle21a::Puzzle21a;
+    type PartB = puzzle21b::Puzzle21b;
+
+    fn name() -> &'static str {
+        "21"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle21);
+
 #[derive(Debug, Clone, Copy)]
 pub enum NumpadButton {
     Number(u32),
     Act,
 }
 
-impl From<char> for NumpadButton {
-    fn from(c: char) -> Self {
-        if let Some(n) = c.to_digit(10) {
-            Self::Number(n)
-        } else {
-            Self::Act
+impl TryFrom<char> for NumpadButton {
+    type Error = PuzzleError;
+
+    fn try_from(c: char) -> Result<Self, PuzzleError> {
+        match c {
+            'A' => Ok(Self::Act),
+            _ => c
+                .to_digit(10)
+                .map(Self::Number)
+                .context(format!("expected a digit or 'A', not '{c}'")),
         }
     }
 }
 
+/// Parses a line like `029A` into its numeric part and the buttons to press.
+pub fn parse_code(line: &str) -> Result<(usize, Vec<NumpadButton>), PuzzleError> {
+    let buttons = line
+        .chars()
+        .map(NumpadButton::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    let num = line
+        .strip_suffix('A')
+        .context("expected the code to end with 'A'")?
+        .parse()?;
+    Ok((num, buttons))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DirpadButton {
     Up,
@@ -44,7 +81,7 @@ impl From<DirpadButton> for char {
 
 impl NumpadButton {
     /// The numpad looks like this:
-    /// ```
+    /// ```text
     /// +---+---+---+
     /// | 7 | 8 | 9 |
     /// +---+---+---+
@@ -164,7 +201,7 @@ type Memo = HashMap<(DirpadButton, Vec<DirpadButton>, usize), (usize, Vec<Dirpad
 
 impl DirpadButton {
     /// The dirpad looks like this:
-    /// ```
+    /// ```text
     ///     +---+---+
     ///     | ^ | A |
     /// +---+---+---+
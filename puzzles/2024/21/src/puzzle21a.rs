@@ -0,0 +1,28 @@
+use crate::{parse_code, Memo, NumpadButton};
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle21a {}
+
+impl PuzzlePart for Puzzle21a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Find the sums of 'complexities' of codes that need to be input by a sequence of robotic arms."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let mut memo: Memo = Memo::new();
+        Ok(input
+            .lines()
+            .enumerate()
+            // "complexity" is the product of the length of the shortest sequence of buttons
+            // (in a 3-layer machine) and the numeric part of the code
+            .map(|(idx, line)| {
+                let (num, seq) = parse_code(line).map_err(|err| err.at_line(idx + 1))?;
+                let num_button = NumpadButton::num_buttons_to_press(&seq, 3, &mut memo);
+                Ok(num_button * num)
+            })
+            .sum::<Result<usize, PuzzleError>>()?
+            .into())
+    }
+}
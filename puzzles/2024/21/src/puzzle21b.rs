@@ -0,0 +1,27 @@
+use crate::{parse_code, Memo, NumpadButton};
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle21b {}
+
+impl PuzzlePart for Puzzle21b {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Find the sums of 'complexities' of codes that need to be input by a sequence of even more robotic arms."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let mut memo: Memo = Memo::new();
+
+        Ok(input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                let (num, seq) = parse_code(line).map_err(|err| err.at_line(idx + 1))?;
+                let num_button = NumpadButton::num_buttons_to_press(&seq, 26, &mut memo);
+                Ok(num_button * num)
+            })
+            .sum::<Result<usize, PuzzleError>>()?
+            .into())
+    }
+}
@@ -1,6 +1,37 @@
+use common::puzzle::PuzzleError;
+
 pub mod puzzle22a;
 pub mod puzzle22b;
 
+pub struct Puzzle22 {}
+
+impl common::puzzle::Puzzle for Puzzle22 {
+    type PartA = puzzle22a::Puzzle22a;
+    type PartB = puzzle22b::Puzzle22b;
+
+    fn name() -> &'static str {
+        "22"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle22);
+
+/// Parses the initial secret number of each monkey, one per line.
+pub fn parse_input(input: &str) -> Result<Vec<u64>, PuzzleError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse()
+                .map_err(|err| PuzzleError::from(err).at_line(idx + 1))
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct SecretNumberIterator {
     prev: u64,
@@ -70,20 +101,6 @@ impl PriceDifferenceIterator {
             prev_price: initial % 10,
         }
     }
-
-    pub fn price_after_seq(&self, seq: [i64; 4]) -> Option<u64> {
-        self.clone()
-            .take(2000)
-            .scan([0; 4], |state, n| {
-                state.copy_within(1.., 0);
-                state[3] = n;
-                Some(*state)
-            })
-            .skip(3)
-            .position(|arr| arr == seq)
-            .and_then(|idx| self.inner.clone().nth(idx + 3))
-            .map(|n| n % 10)
-    }
 }
 
 impl Iterator for PriceDifferenceIterator {
@@ -97,44 +114,3 @@ impl Iterator for PriceDifferenceIterator {
         Some(diff)
     }
 }
-
-#[derive(Debug, Clone)]
-pub struct DiffSeqIterator {
-    prev: [i64; 4],
-}
-
-impl Default for DiffSeqIterator {
-    fn default() -> Self {
-        Self {
-            prev: [-10, -9, -9, -9],
-        }
-    }
-}
-
-impl DiffSeqIterator {
-    pub fn new() -> Self {
-        Default::default()
-    }
-}
-
-impl Iterator for DiffSeqIterator {
-    type Item = [i64; 4];
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.prev == [9, 9, 9, 9] {
-            return None;
-        }
-
-        let mut next = self.prev;
-        next[0] += 1;
-        let mut idx = 0;
-        while next[idx] == 10 {
-            next[idx] = -9;
-            idx += 1;
-            next[idx] += 1;
-        }
-
-        self.prev = next;
-        Some(next)
-    }
-}
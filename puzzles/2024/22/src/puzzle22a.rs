@@ -0,0 +1,26 @@
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+use crate::{parse_input, SecretNumberIterator};
+
+pub struct Puzzle22a {}
+
+impl PuzzlePart for Puzzle22a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Find the sum of the 2000th numbers generated by a bunch of hashing monkeys."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let initial_values = parse_input(input)?;
+
+        Ok(initial_values
+            .into_iter()
+            .map(|v| {
+                let mut iter = SecretNumberIterator::new(v);
+                iter.nth(1999).unwrap()
+            })
+            .sum::<u64>()
+            .into())
+    }
+}
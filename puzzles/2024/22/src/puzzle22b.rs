@@ -0,0 +1,39 @@
+use crate::{parse_input, PriceDifferenceIterator, SecretNumberIterator};
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+use std::collections::{HashMap, HashSet};
+
+pub struct Puzzle22b {}
+
+impl PuzzlePart for Puzzle22b {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Find the most number of bananas that can be obtained by giving a monkey a sequence of four changes to look for in some hashing prices."
+    }
+
+    /// strat: rather than trying every sequence of four changes on every monkey,
+    /// walk through each monkey's prices once, and add the price it sells at to the
+    /// total for each sequence of changes the first time that sequence comes up
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let inputs = parse_input(input)?;
+        let mut bananas: HashMap<[i64; 4], u64> = HashMap::new();
+
+        for initial in inputs {
+            let diffs = PriceDifferenceIterator::new(initial);
+            let prices = SecretNumberIterator::new(initial).map(|n| n % 10);
+
+            // a monkey sells the first time it sees the sequence, so later ones don't count
+            let mut seen = HashSet::new();
+            let mut seq = [0; 4];
+            for (idx, (diff, price)) in diffs.zip(prices).take(2000).enumerate() {
+                seq.copy_within(1.., 0);
+                seq[3] = diff;
+                if idx >= 3 && seen.insert(seq) {
+                    *bananas.entry(seq).or_default() += price;
+                }
+            }
+        }
+
+        Ok(bananas.into_values().max().unwrap_or_default().into())
+    }
+}
@@ -1,19 +1,42 @@
+use common::puzzle::{Context, PuzzleError};
 use std::collections::{BTreeSet, HashMap, HashSet};
 
 pub mod puzzle23a;
 pub mod puzzle23b;
 
+pub struct Puzzle23 {}
+
+impl common::puzzle::Puzzle for Puzzle23 {
+    type PartA = puzzle23a::Puzzle23a;
+    type PartB = puzzle23b::Puzzle23b;
+
+    fn name() -> &'static str {
+        "23"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle23);
+
 /// A graph stored as a map node -> neighbors
 #[derive(Debug)]
 pub struct Network<'a>(HashMap<&'a str, Vec<&'a str>>);
 
 impl<'a> Network<'a> {
-    pub fn parse(input: &'a str) -> Option<Self> {
+    pub fn parse(input: &'a str) -> Result<Self, PuzzleError> {
         // each line is an edge; parse into pairs
         let edges = input
             .lines()
-            .map(|line| line.split_once('-'))
-            .collect::<Option<Vec<_>>>()?;
+            .enumerate()
+            .map(|(idx, line)| {
+                line.split_once('-')
+                    .context("expected an edge like \"ab-cd\"")
+                    .map_err(|err| err.at_line(idx + 1))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         // create a list of nodes, then iterate through it to build the adjacently map
         let nodes: HashSet<&str> = edges
@@ -42,7 +65,7 @@ impl<'a> Network<'a> {
             })
             .collect();
 
-        Some(Self(node_map))
+        Ok(Self(node_map))
     }
 
     /// Iterates over all of the complete subgraphs in this network
@@ -1,25 +1,27 @@
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 use crate::Network;
 
 pub struct Puzzle23a {}
 
 impl PuzzlePart for Puzzle23a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Count the number of sets of three interconnected nodes in a computer network where at least one node starts with 't'."
     }
 
-    fn solve(input: &str) -> String {
-        let graph = Network::parse(input).unwrap();
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let graph = Network::parse(input)?;
 
         let mut subgraphs = graph.complete_subgraphs();
 
-        subgraphs
-            .nth(3) // size 3 subgraphs
-            .unwrap()
+        Ok(subgraphs
+            .nth(3) // size 3 subgraphs, if there are any
+            .unwrap_or_default()
             .iter()
             .filter(|nodes| nodes.iter().any(|node| node.starts_with('t')))
             .count()
-            .to_string()
+            .into())
     }
 }
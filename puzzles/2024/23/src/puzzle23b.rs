@@ -1,26 +1,28 @@
 use crate::Network;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, Context, PuzzleError, PuzzlePart};
 
 pub struct Puzzle23b {}
 
 impl PuzzlePart for Puzzle23b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Find the 'password' obtained by sorting the nodes in the *largest* connect subgraph of a network."
     }
 
-    fn solve(input: &str) -> String {
-        let graph = Network::parse(input).unwrap();
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let graph = Network::parse(input)?;
 
         let subgraphs = graph.complete_subgraphs();
         let mut largest_subgraph = subgraphs
             .last()
             .and_then(|g| g.into_iter().next())
-            .unwrap()
+            .context("expected at least one connection")?
             .into_iter()
             .collect::<Vec<_>>();
 
         largest_subgraph.sort_unstable();
 
-        largest_subgraph.join(",")
+        Ok(largest_subgraph.join(",").into())
     }
 }
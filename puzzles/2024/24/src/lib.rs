@@ -1,8 +1,26 @@
+use common::puzzle::{Context, PuzzleError};
 use std::{collections::VecDeque, fmt::Display};
 
 pub mod puzzle24a;
 pub mod puzzle24b;
 
+pub struct Puzzle24 {}
+
+impl common::puzzle::Puzzle for Puzzle24 {
+    type PartA = puzzle24a::Puzzle24a;
+    type PartB = puzzle24b::Puzzle24b;
+
+    fn name() -> &'static str {
+        "24"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle24, b: ignore = "part B has no example (it looks for swapped wires in the real input)");
+
 #[derive(Debug, Clone)]
 pub struct Wire<'a> {
     name: &'a str,
@@ -24,7 +42,7 @@ impl TryFrom<&str> for Op {
             "AND" => Ok(Self::And),
             "OR" => Ok(Self::Or),
             "XOR" => Ok(Self::Xor),
-            _ => Err(String::from("uh oh!")),
+            _ => Err(format!("unknown gate '{s}'")),
         }
     }
 }
@@ -59,29 +77,38 @@ pub struct Device<'a> {
 }
 
 impl<'a> Device<'a> {
-    pub fn parse(input: &'a str) -> Option<Self> {
+    pub fn parse(input: &'a str) -> Result<Self, PuzzleError> {
         // two chunks: first inputs, then gates
-        let (inputs_str, gates_str) = input.split_once("\n\n")?;
+        let (inputs_str, gates_str) = input
+            .split_once("\n\n")
+            .context("expected a blank line between the inputs and the gates")?;
 
         let pre_inputs = inputs_str
             .lines()
             .map(|line| {
-                let (name, val_str) = line.split_once(": ")?;
-                Some((name, val_str == "1"))
+                let (name, val_str) = line
+                    .split_once(": ")
+                    .context("expected an input like \"x00: 1\"")
+                    .map_err(|err| err.at_fragment(input, line))?;
+                Ok((name, val_str == "1"))
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<Result<Vec<_>, PuzzleError>>()?;
 
         let pre_gates = gates_str
             .lines()
             .map(|line| {
-                let (in_wire1, rest) = line.split_once(' ')?;
-                let (op_str, rest) = rest.split_once(' ')?;
-                let (in_wire2, out_wire) = rest.split_once(" -> ")?;
-                let op = Op::try_from(op_str).ok()?;
+                let parse_gate = || -> Result<_, PuzzleError> {
+                    let (in_wire1, rest) = line.split_once(' ').context("missing gate")?;
+                    let (op_str, rest) = rest.split_once(' ').context("missing second input")?;
+                    let (in_wire2, out_wire) = rest.split_once(" -> ").context("missing output")?;
+                    let op = Op::try_from(op_str)?;
+
+                    Ok((in_wire1, op, in_wire2, out_wire))
+                };
 
-                Some((in_wire1, op, in_wire2, out_wire))
+                parse_gate().map_err(|err| err.at_fragment(input, line))
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<Result<Vec<_>, _>>()?;
 
         // start list of wires from inputs
         let mut wires = pre_inputs
@@ -108,7 +135,8 @@ impl<'a> Device<'a> {
                 let wire_idx = wires.iter().position(|w| w.name == name)?;
                 Some(Input { output: wire_idx })
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<Option<Vec<_>>>()
+            .context("input refers to an unknown wire")?;
 
         // create gates
         let gates = pre_gates
@@ -123,16 +151,17 @@ impl<'a> Device<'a> {
                     output: out_idx,
                 })
             })
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<Option<Vec<_>>>()
+            .context("gate refers to an unknown wire")?;
 
-        Some(Self {
+        Ok(Self {
             inputs,
             gates,
             wires,
         })
     }
 
-    pub fn compute(&mut self) {
+    pub fn compute(&mut self) -> Result<(), PuzzleError> {
         // keep track of a list of which wires (by index in `self.wires`) have values.
         // to start, those are exactly the indices that correspond to inputs
         let mut powered_wires = self.inputs.iter().map(|i| i.output).collect::<Vec<_>>();
@@ -146,8 +175,13 @@ impl<'a> Device<'a> {
 
         while let Some(gate) = gates_to_check.pop_front() {
             // compute the value of the output, then set that wire
-            let input1 = self.wires[gate.input.0].value.unwrap();
-            let input2 = self.wires[gate.input.1].value.unwrap();
+            let wire_value = |idx: usize| {
+                let wire = &self.wires[idx];
+                wire.value
+                    .context(format!("wire {} has no value yet", wire.name))
+            };
+            let input1 = wire_value(gate.input.0)?;
+            let input2 = wire_value(gate.input.1)?;
             let output = gate.op.eval(input1, input2);
             self.wires[gate.output].value = Some(output);
             powered_wires.push(gate.output);
@@ -160,6 +194,7 @@ impl<'a> Device<'a> {
             });
             gates_to_check.append(&mut gates_to_add);
         }
+        Ok(())
     }
 
     pub fn inputs(&self) -> (usize, usize) {
@@ -194,42 +229,51 @@ impl<'a> Device<'a> {
             .sum()
     }
 
-    pub fn tree_for_output(&self, output: &'a str) -> OpTree<'a> {
-        let idx = self.wires.iter().position(|w| w.name == output).unwrap();
-        self.tree_for_output_index(idx)
+    fn wire_index(&self, name: &str) -> Result<usize, PuzzleError> {
+        self.wires
+            .iter()
+            .position(|w| w.name == name)
+            .context(format!("no wire named {name}"))
+    }
+
+    fn gate_index(&self, output: usize) -> Result<usize, PuzzleError> {
+        self.gates
+            .iter()
+            .position(|g| g.output == output)
+            .context(format!(
+                "no gate outputs to wire {}",
+                self.wires[output].name
+            ))
+    }
+
+    pub fn tree_for_output(&self, output: &'a str) -> Result<OpTree<'a>, PuzzleError> {
+        self.tree_for_output_index(self.wire_index(output)?)
     }
 
-    fn tree_for_output_index(&self, output: usize) -> OpTree<'a> {
+    fn tree_for_output_index(&self, output: usize) -> Result<OpTree<'a>, PuzzleError> {
         if self.inputs.iter().any(|i| i.output == output) {
-            OpTree::Input(self.wires[output].name)
+            Ok(OpTree::Input(self.wires[output].name))
         } else {
-            let gate = self.gates.iter().find(|g| g.output == output).unwrap();
-            let left = self.tree_for_output_index(gate.input.0);
-            let right = self.tree_for_output_index(gate.input.1);
-            OpTree::Node {
+            let gate = &self.gates[self.gate_index(output)?];
+            let left = self.tree_for_output_index(gate.input.0)?;
+            let right = self.tree_for_output_index(gate.input.1)?;
+            Ok(OpTree::Node {
                 op: gate.op.clone(),
                 left: Box::new(left),
                 right: Box::new(right),
-            }
+            })
         }
     }
 
-    pub fn swap_outputs(&mut self, left: &str, right: &str) {
-        let left_idx = self.wires.iter().position(|w| w.name == left).unwrap();
-        let right_idx = self.wires.iter().position(|w| w.name == right).unwrap();
-        let left_gate_idx = self
-            .gates
-            .iter()
-            .position(|g| g.output == left_idx)
-            .unwrap();
-        let right_gate_idx = self
-            .gates
-            .iter()
-            .position(|g| g.output == right_idx)
-            .unwrap();
+    pub fn swap_outputs(&mut self, left: &str, right: &str) -> Result<(), PuzzleError> {
+        let left_idx = self.wire_index(left)?;
+        let right_idx = self.wire_index(right)?;
+        let left_gate_idx = self.gate_index(left_idx)?;
+        let right_gate_idx = self.gate_index(right_idx)?;
 
         self.gates[left_gate_idx].output = right_idx;
         self.gates[right_gate_idx].output = left_idx;
+        Ok(())
     }
 }
 
@@ -0,0 +1,19 @@
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+use crate::Device;
+
+pub struct Puzzle24a {}
+
+impl PuzzlePart for Puzzle24a {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Compute the decimal number output by a digital circuit of logic gates."
+    }
+
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let mut device = Device::parse(input)?;
+        device.compute()?;
+        Ok(device.output().into())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::Device;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 use itertools::Itertools;
 
 pub struct Puzzle24b {}
@@ -9,12 +9,14 @@ pub struct Puzzle24b {}
 // for each z__ wire, then figured out what wires needed to be swapped by inspection
 // and by testing various inputs on the x__ and y__ wires.
 impl PuzzlePart for Puzzle24b {
+    type Params = ();
+
     fn description() -> &'static str {
         "Find the four pairs of gates whose outputs needs to be swapped to turn the device into an integer adder."
     }
 
-    fn solve(input: &str) -> String {
-        let mut device = Device::parse(input).unwrap();
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        let mut device = Device::parse(input)?;
 
         let mut pairs = [
             ["cdj", "z08"],
@@ -23,7 +25,7 @@ impl PuzzlePart for Puzzle24b {
             ["z16", "mrb"],
         ];
         for [left, right] in pairs {
-            device.swap_outputs(left, right);
+            device.swap_outputs(left, right)?;
         }
 
         // NOTE: uncomment the two blocks below to print
@@ -38,20 +40,22 @@ impl PuzzlePart for Puzzle24b {
 
         // for w in z_wires {
         //     let output = w.name;
-        //     let mut tree = device.tree_for_output(output);
+        //     let mut tree = device.tree_for_output(output)?;
         //     tree.sort_nodes();
         //     println!("{output} = {tree}")
         // }
 
-        device.compute();
+        device.compute()?;
 
         let (x, y) = device.inputs();
         let z = device.output();
 
-        assert_eq!(x + y, z);
+        if x + y != z {
+            return Err(format!("the swaps don't fix this device: {x} + {y} gives {z}").into());
+        }
 
         let all_strs = pairs.as_flattened_mut();
         all_strs.sort_unstable();
-        all_strs.iter().join(",")
+        Ok(all_strs.iter().join(",").into())
     }
 }
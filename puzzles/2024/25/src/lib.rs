@@ -1,6 +1,23 @@
 pub mod puzzle25a;
 pub mod puzzle25b;
 
+pub struct Puzzle25 {}
+
+impl common::puzzle::Puzzle for Puzzle25 {
+    type PartA = puzzle25a::Puzzle25a;
+    type PartB = puzzle25b::Puzzle25b;
+
+    fn name() -> &'static str {
+        "25"
+    }
+
+    fn year() -> u32 {
+        2024
+    }
+}
+
+common::example_tests!(Puzzle25, b: ignore = "there is no part B");
+
 #[derive(Debug, Clone)]
 pub struct Key([u8; 5]);
 
@@ -1,20 +1,22 @@
 use crate::parse_locks_and_keys;
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct Puzzle25a {}
 
 impl PuzzlePart for Puzzle25a {
+    type Params = ();
+
     fn description() -> &'static str {
         "Determine how many 5-pin lock/key pairs fit (loosely) together."
     }
 
-    fn solve(input: &str) -> String {
+    fn solve(input: &str, _params: &()) -> Result<Answer, PuzzleError> {
         let (locks, keys) = parse_locks_and_keys(input);
 
-        locks
+        Ok(locks
             .iter()
             .map(|lock| keys.iter().filter(|key| key.loosely_fits(lock)).count())
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 }
@@ -0,0 +1,15 @@
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
+
+pub struct Puzzle25b {}
+
+impl PuzzlePart for Puzzle25b {
+    type Params = ();
+
+    fn description() -> &'static str {
+        "Puzzle 25 Part B"
+    }
+
+    fn solve(_input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok("Nothing to do here, we're done!".into())
+    }
+}
@@ -1,13 +0,0 @@
-use common::puzzle::PuzzlePart;
-
-pub struct Puzzle25b {}
-
-impl PuzzlePart for Puzzle25b {
-    fn description() -> &'static str {
-        "Puzzle 25 Part B"
-    }
-
-    fn solve(_input: &str) -> String {
-        "Nothing to do here, we're done!".into()
-    }
-}
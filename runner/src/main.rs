@@ -0,0 +1,413 @@
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+use common::puzzle::{
+    Answer, BenchArgs, Bounds, DaySelection, History, HistoryEntry, HttpFetcher, InputCache, Mark,
+    Puzzle, PuzzleArgs, PuzzleEntry, PuzzlePage, PuzzlePartName, Registry, TrackingAllocator,
+    DEFAULT_BASE_URL,
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+mod scaffold;
+mod watch;
+
+/// The root of the workspace, which holds the `puzzles` directory.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+/// The year of the first Advent of Code.
+const FIRST_YEAR: u32 = 2015;
+
+/// Where the adventofcode.com session cookie is kept, unless `AOC_SESSION` is set.
+const SESSION_FILE: &str = "setup/get-input/.session-cookie";
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code puzzle solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Which year's puzzles to work with [default: the latest year with any puzzles]
+    #[arg(long, short, global = true)]
+    year: Option<u32>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days' puzzles
+    Run {
+        /// Which day(s) to run: a single day (eg. `6`) or a range (eg. `1..=25`).
+        /// Runs every day if omitted.
+        days: Option<DaySelection>,
+
+        #[command(flatten)]
+        args: PuzzleArgs,
+
+        /// Keep running, rebuilding and rerunning each day whenever its `src` or
+        /// `data` directory changes
+        #[arg(long)]
+        watch: bool,
+    },
+
+    /// Time many runs of one or more days' puzzles
+    Bench {
+        /// Which day(s) to benchmark: a single day (eg. `6`) or a range (eg. `1..=25`).
+        /// Benchmarks every day if omitted.
+        days: Option<DaySelection>,
+
+        #[command(flatten)]
+        args: PuzzleArgs,
+
+        #[command(flatten)]
+        bench_args: BenchArgs,
+    },
+
+    /// Download puzzle inputs into each day's `data` directory, via a local
+    /// cache so that no input is ever downloaded twice
+    Fetch {
+        /// Which day(s) to fetch: a single day (eg. `6`) or a range (eg. `1..=25`).
+        /// Fetches every day if omitted.
+        days: Option<DaySelection>,
+
+        /// The server to download inputs from
+        #[arg(long, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Where to cache downloaded inputs [default: .cache/inputs]
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+
+        /// Replace inputs that are already in the puzzles' `data` directories
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Save code blocks from a puzzle's description page (saved as HTML) as
+    /// examples, and list the answers it emphasizes
+    Example {
+        /// Which day's examples to save
+        day: u32,
+
+        /// The saved puzzle description page
+        page: PathBuf,
+
+        /// Which code block(s) to save, counting from 0
+        #[arg(long, short, default_value = "0")]
+        block: Vec<usize>,
+
+        /// Just list the code blocks and answers, without saving anything
+        #[arg(long, short)]
+        list: bool,
+    },
+
+    /// Record what adventofcode.com said about an answer, so that later runs can
+    /// warn about answers that can't be right
+    Mark {
+        /// Which day's answer to mark
+        day: u32,
+
+        /// Which part the answer is for
+        part: PuzzlePartName,
+
+        /// What happened when the answer was submitted
+        mark: Mark,
+
+        /// The answer to mark [default: the part's latest answer]
+        #[arg(value_parser = |s: &str| s.parse::<Answer>())]
+        answer: Option<Answer>,
+    },
+
+    /// Check an answer against the marks in a day's history, as a stand-in for
+    /// submitting it, without going online
+    Submit {
+        /// Which day's answer to check
+        day: u32,
+
+        /// Which part the answer is for
+        part: PuzzlePartName,
+
+        /// The answer to check [default: the part's latest answer]
+        #[arg(value_parser = |s: &str| s.parse::<Answer>())]
+        answer: Option<Answer>,
+    },
+
+    /// Create a new day's puzzle crate from the template and register it with the runner
+    Scaffold {
+        /// Which day to create (1 through 25), in the year given by `--year`
+        day: u32,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let entries = entries();
+    let year = cli
+        .year
+        .or_else(|| entries.iter().map(|entry| entry.year).max())
+        .unwrap_or(FIRST_YEAR);
+    let registry = Registry::new(
+        Path::new(ROOT).join("puzzles"),
+        entries
+            .into_iter()
+            .filter(|entry| entry.year == year)
+            .collect(),
+    );
+
+    match cli.command {
+        Command::Run { days, args, watch } => {
+            let days = days.unwrap_or_else(DaySelection::all);
+            let ok = if watch {
+                watch::watch(Path::new(ROOT), &registry, &days, &args)
+            } else {
+                registry.run(&days, &args)
+            };
+
+            if !ok {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            args,
+            bench_args,
+        } => {
+            if !registry.bench(&days.unwrap_or_else(DaySelection::all), &args, &bench_args) {
+                process::exit(1);
+            }
+        }
+        Command::Fetch {
+            days,
+            base_url,
+            cache_dir,
+            force,
+        } => {
+            let fetcher = HttpFetcher::new(session()).with_base_url(base_url);
+            let cache_dir = cache_dir.unwrap_or_else(|| Path::new(ROOT).join(".cache/inputs"));
+            let cache = InputCache::new(cache_dir, fetcher);
+
+            if !registry.fetch(&days.unwrap_or_else(DaySelection::all), &cache, force) {
+                process::exit(1);
+            }
+        }
+        Command::Example {
+            day,
+            page,
+            block,
+            list,
+        } => {
+            if let Err(msg) = extract_examples(&registry, day, &page, &block, list) {
+                eprintln!("{} {msg}", "Error:".red().bold());
+                process::exit(1);
+            }
+        }
+        Command::Mark {
+            day,
+            part,
+            mark,
+            answer,
+        } => {
+            if let Err(msg) = mark_answer(&registry, day, part, mark, answer) {
+                eprintln!("{} {msg}", "Error:".red().bold());
+                process::exit(1);
+            }
+        }
+        Command::Submit { day, part, answer } => {
+            match submit_answer(&registry, day, part, answer) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(msg) => {
+                    eprintln!("{} {msg}", "Error:".red().bold());
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Scaffold { day } => match scaffold::scaffold(Path::new(ROOT), year, day) {
+            Ok(paths) => {
+                println!("Set up {}:", format!("{year} Day {day}").green().bold());
+                for path in paths {
+                    let path = path.strip_prefix(ROOT).unwrap_or(&path);
+                    println!("  {}", path.display().to_string().cyan());
+                }
+                println!("{}", "Good luck!".magenta().bold());
+            }
+            Err(msg) => {
+                eprintln!("{} {msg}", "Error:".red().bold());
+                process::exit(1);
+            }
+        },
+    }
+}
+
+/// Lists the code blocks on a saved puzzle page, or saves some of them as the
+/// day's examples, then lists the answers that the page emphasizes.
+fn extract_examples(
+    registry: &Registry,
+    day: u32,
+    page: &Path,
+    blocks: &[usize],
+    list: bool,
+) -> Result<(), String> {
+    let html = fs::read_to_string(page)
+        .map_err(|err| format!("could not read {}: {err}", page.display()))?;
+    let page = PuzzlePage::parse(&html);
+
+    if list {
+        for (idx, block) in page.blocks.iter().enumerate() {
+            let first_line = block.lines().next().unwrap_or_default();
+            let lines = match block.lines().count() {
+                1 => "(1 line)".to_string(),
+                n => format!("({n} lines)"),
+            };
+            println!(
+                "{} {first_line} {}",
+                format!("[{idx}]").blue(),
+                lines.dimmed()
+            );
+        }
+    } else {
+        let entry = registry
+            .entries()
+            .find(|entry| entry.day() == Some(day))
+            .ok_or_else(|| format!("there's no puzzle for day {day}"))?;
+        let names = page.save_examples(&registry.data_dir(entry), blocks)?;
+
+        for (idx, name) in blocks.iter().zip(&names) {
+            println!(
+                "Saved code block {idx} as {}",
+                format!("example {name}").cyan()
+            );
+        }
+
+        if let Some(path) = page.save_candidates(&registry.data_dir(entry), &names)? {
+            println!("Noted the possible answers in {}", path.display());
+        }
+    }
+
+    if !page.answers.is_empty() {
+        println!("Possible answers: {}", page.answers.join(", ").green());
+    }
+
+    Ok(())
+}
+
+/// Adds a mark for an answer (or the latest one) to a day's history.
+fn mark_answer(
+    registry: &Registry,
+    day: u32,
+    part: PuzzlePartName,
+    mark: Mark,
+    answer: Option<Answer>,
+) -> Result<(), String> {
+    let (entry, history) = day_history(registry, day)?;
+    let answer = answer_or_latest(answer, &history, part)?;
+    History::append(
+        &registry.data_dir(entry),
+        &HistoryEntry {
+            mark: Some(mark),
+            ..HistoryEntry::new(part, answer.clone())
+        },
+    )?;
+
+    println!(
+        "Marked {} answer {} as {}",
+        format!("Puzzle {} Part {part}", entry.name).blue(),
+        answer.to_string().bold(),
+        mark.to_string().cyan()
+    );
+    Ok(())
+}
+
+/// Judges an answer (or the latest one) by the bounds from a day's history,
+/// returning whether it could be right.
+fn submit_answer(
+    registry: &Registry,
+    day: u32,
+    part: PuzzlePartName,
+    answer: Option<Answer>,
+) -> Result<bool, String> {
+    let (entry, history) = day_history(registry, day)?;
+    let answer = answer_or_latest(answer, &history, part)?;
+    let bounds = Bounds::new(&history, part);
+    let verdict = bounds.judge(&answer);
+
+    let outcome = match (verdict.is_plausible(), verdict.to_string()) {
+        (true, verdict) => format!("✔ plausible, {verdict}").green(),
+        (false, verdict) => format!("✘ wrong, {verdict}").red(),
+    };
+    println!(
+        "{} answer {}: {outcome} {}",
+        format!("Puzzle {} Part {part}", entry.name).blue(),
+        answer.to_string().bold(),
+        format!("({bounds})").dimmed()
+    );
+    Ok(verdict.is_plausible())
+}
+
+/// Finds a day's puzzle, and reads its history.
+fn day_history(registry: &Registry, day: u32) -> Result<(&PuzzleEntry, History), String> {
+    let entry = registry
+        .entries()
+        .find(|entry| entry.day() == Some(day))
+        .ok_or_else(|| format!("there's no puzzle for day {day}"))?;
+    let history = History::load(&registry.data_dir(entry))?;
+
+    Ok((entry, history))
+}
+
+fn answer_or_latest(
+    answer: Option<Answer>,
+    history: &History,
+    part: PuzzlePartName,
+) -> Result<Answer, String> {
+    match answer {
+        Some(answer) => Ok(answer),
+        None => history
+            .latest_answer(part)
+            .cloned()
+            .ok_or_else(|| format!("there are no answers to part {part} yet")),
+    }
+}
+
+/// The adventofcode.com session cookie, from `AOC_SESSION` or `SESSION_FILE`.
+/// Empty if there isn't one, in which case fetching will fail.
+fn session() -> String {
+    env::var("AOC_SESSION")
+        .or_else(|_| fs::read_to_string(Path::new(ROOT).join(SESSION_FILE)))
+        .unwrap_or_default()
+}
+
+/// Every puzzle that the runner knows about.
+fn entries() -> Vec<PuzzleEntry> {
+    vec![
+        puzzle2024_01::Puzzle01::entry(),
+        puzzle2024_02::Puzzle02::entry(),
+        puzzle2024_03::Puzzle03::entry(),
+        puzzle2024_04::Puzzle04::entry(),
+        puzzle2024_05::Puzzle05::entry(),
+        puzzle2024_06::Puzzle06::entry(),
+        puzzle2024_07::Puzzle07::entry(),
+        puzzle2024_08::Puzzle08::entry(),
+        puzzle2024_09::Puzzle09::entry(),
+        puzzle2024_10::Puzzle10::entry(),
+        puzzle2024_11::Puzzle11::entry(),
+        puzzle2024_12::Puzzle12::entry(),
+        puzzle2024_13::Puzzle13::entry(),
+        puzzle2024_14::Puzzle14::entry(),
+        puzzle2024_15::Puzzle15::entry(),
+        puzzle2024_16::Puzzle16::entry(),
+        puzzle2024_17::Puzzle17::entry(),
+        puzzle2024_18::Puzzle18::entry(),
+        puzzle2024_19::Puzzle19::entry(),
+        puzzle2024_20::Puzzle20::entry(),
+        puzzle2024_21::Puzzle21::entry(),
+        puzzle2024_22::Puzzle22::entry(),
+        puzzle2024_23::Puzzle23::entry(),
+        puzzle2024_24::Puzzle24::entry(),
+        puzzle2024_25::Puzzle25::entry(),
+    ]
+}
@@ -0,0 +1,282 @@
+use crate::FIRST_YEAR;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files in `setup/template`, baked into the binary. `DAYNUM` in their paths
+/// and contents is replaced by the (zero-padded) day number, and `YEARNUM` by the year.
+const TEMPLATE: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../../setup/template/Cargo.toml"),
+    ),
+    ("justfile", include_str!("../../setup/template/justfile")),
+    (
+        "src/lib.rs",
+        include_str!("../../setup/template/src/lib.rs"),
+    ),
+    (
+        "src/main.rs",
+        include_str!("../../setup/template/src/main.rs"),
+    ),
+    (
+        "src/puzzleDAYNUMa.rs",
+        include_str!("../../setup/template/src/puzzleDAYNUMa.rs"),
+    ),
+    (
+        "src/puzzleDAYNUMb.rs",
+        include_str!("../../setup/template/src/puzzleDAYNUMb.rs"),
+    ),
+];
+
+/// The answers file for a new day, with nothing filled in yet.
+const ANSWERS_PLACEHOLDER: &str = "\
+# Known-good answers, checked by `--check` and `cargo test`.
+# Each [examples.<name>] table belongs to data/examples/<name>.
+
+[examples.1]
+# a = 0
+# b = 0
+
+[input]
+# a = 0
+# b = 0
+";
+
+/// Creates `puzzles/<year>/<day>` in the workspace at `root` from the template,
+/// with a `data` directory holding placeholder example files, and registers the
+/// new crate with the runner. Refuses to touch a day that already exists.
+///
+/// Returns the paths of the files that were created or changed.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("{day} is not a valid day (expected 1 through 25)"));
+    }
+    if year < FIRST_YEAR {
+        return Err(format!(
+            "{year} is not a valid year (the first was {FIRST_YEAR})"
+        ));
+    }
+
+    let day_num = format!("{day:02}");
+    let puzzle_dir = root.join("puzzles").join(year.to_string()).join(&day_num);
+    if puzzle_dir.exists() {
+        return Err(format!("{} already exists", puzzle_dir.display()));
+    }
+
+    // work out the changes to the runner first, so that nothing is written if they can't be made
+    let manifest_path = root.join("runner/Cargo.toml");
+    let main_path = root.join("runner/src/main.rs");
+    let manifest = register_dependency(&read(&manifest_path)?, year, day)?;
+    let main = register_entry(&read(&main_path)?, year, day)?;
+
+    let mut files = TEMPLATE
+        .iter()
+        .map(|(path, contents)| {
+            (
+                puzzle_dir.join(path.replace("DAYNUM", &day_num)),
+                contents
+                    .replace("DAYNUM", &day_num)
+                    .replace("YEARNUM", &year.to_string()),
+            )
+        })
+        .collect::<Vec<_>>();
+    files.push((puzzle_dir.join("data/examples/1"), String::new()));
+    files.push((
+        puzzle_dir.join("data/answers.toml"),
+        ANSWERS_PLACEHOLDER.into(),
+    ));
+    files.push((manifest_path, manifest));
+    files.push((main_path, main));
+
+    for (path, contents) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+        }
+        fs::write(path, contents)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+/// Adds the day's crate to the runner's dependencies, keeping them in order.
+fn register_dependency(manifest: &str, year: u32, day: u32) -> Result<String, String> {
+    let line = format!("puzzle{year}_{day:02} = {{ path = \"../puzzles/{year}/{day:02}\" }}");
+    let day_of = |line: &str| {
+        let rest = line.strip_prefix("puzzle")?;
+        crate_day(rest.split_once(" = ")?.0)
+    };
+
+    insert_in_order(manifest, &line, (year, day), day_of, "[dependencies]")
+}
+
+/// Adds the day's puzzle to the list in the runner's `entries()`, keeping it in order.
+fn register_entry(main: &str, year: u32, day: u32) -> Result<String, String> {
+    let line = format!("        puzzle{year}_{day:02}::Puzzle{day:02}::entry(),");
+    let day_of = |line: &str| {
+        let rest = line.trim().strip_prefix("puzzle")?;
+        let (name, rest) = rest.split_once("::")?;
+        rest.ends_with("::entry(),").then(|| crate_day(name))?
+    };
+
+    insert_in_order(main, &line, (year, day), day_of, "    vec![")
+}
+
+/// The year and day of a puzzle crate, from its name (without the `puzzle` prefix).
+fn crate_day(name: &str) -> Option<(u32, u32)> {
+    let (year, day) = name.split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Inserts `new_line` among the lines that belong to other days (as told by `day_of`)
+/// so that they stay sorted, or just after `anchor` if there aren't any yet.
+fn insert_in_order(
+    text: &str,
+    new_line: &str,
+    day: (u32, u32),
+    day_of: impl Fn(&str) -> Option<(u32, u32)>,
+    anchor: &str,
+) -> Result<String, String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    if lines.contains(&new_line) {
+        return Ok(text.to_string());
+    }
+
+    let idx = lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|other| other > day))
+        .or_else(|| {
+            let last = lines.iter().rposition(|line| day_of(line).is_some())?;
+            Some(last + 1)
+        })
+        .or_else(|| Some(lines.iter().position(|line| *line == anchor)? + 1))
+        .ok_or_else(|| format!("could not find where to register the puzzle (no '{anchor}')"))?;
+
+    lines.insert(idx, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_register_dependency_in_order() {
+        let manifest = "[dependencies]\ncommon = { workspace = true }\npuzzle2023_25 = { path = \"../puzzles/2023/25\" }\npuzzle2024_01 = { path = \"../puzzles/2024/01\" }\npuzzle2024_03 = { path = \"../puzzles/2024/03\" }\n";
+
+        assert_eq!(
+            register_dependency(manifest, 2024, 2).unwrap(),
+            "[dependencies]\ncommon = { workspace = true }\npuzzle2023_25 = { path = \"../puzzles/2023/25\" }\npuzzle2024_01 = { path = \"../puzzles/2024/01\" }\npuzzle2024_02 = { path = \"../puzzles/2024/02\" }\npuzzle2024_03 = { path = \"../puzzles/2024/03\" }\n"
+        );
+        assert!(register_dependency(manifest, 2022, 4)
+            .unwrap()
+            .contains("}\npuzzle2022_04 = { path = \"../puzzles/2022/04\" }\npuzzle2023_25"));
+    }
+
+    #[test]
+    fn should_register_first_entry() {
+        let main = "fn entries() -> Vec<PuzzleEntry> {\n    vec![\n    ]\n}\n";
+
+        assert_eq!(
+            register_entry(main, 2024, 7).unwrap(),
+            "fn entries() -> Vec<PuzzleEntry> {\n    vec![\n        puzzle2024_07::Puzzle07::entry(),\n    ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn should_not_register_twice() {
+        let main = "    vec![\n        puzzle2024_07::Puzzle07::entry(),\n    ]\n";
+        assert_eq!(register_entry(main, 2024, 7).unwrap(), main);
+    }
+
+    /// A stand-in for the workspace, with a runner that has one day registered.
+    fn fake_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+        fs::create_dir_all(root.join("puzzles/2024/06")).unwrap();
+        fs::write(
+            root.join("runner/Cargo.toml"),
+            "[dependencies]\npuzzle2024_06 = { path = \"../puzzles/2024/06\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("runner/src/main.rs"),
+            "    vec![\n        puzzle2024_06::Puzzle06::entry(),\n    ]\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn should_scaffold_new_day() {
+        let root = fake_root("new");
+        let files = scaffold(&root, 2024, 7).unwrap();
+
+        let day_dir = root.join("puzzles/2024/07");
+        assert!(files.contains(&day_dir.join("src/puzzle07a.rs")));
+        assert!(fs::read_to_string(day_dir.join("src/main.rs"))
+            .unwrap()
+            .contains("puzzle2024_07::Puzzle07"));
+        assert!(day_dir.join("data/examples/1").exists());
+        assert!(fs::read_to_string(root.join("runner/src/main.rs"))
+            .unwrap()
+            .contains("puzzle2024_07::Puzzle07::entry(),"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn should_scaffold_day_whose_tests_pass() {
+        let root = fake_root("build");
+        let real_root = Path::new(crate::ROOT);
+        let manifest = fs::read_to_string(real_root.join("Cargo.toml"))
+            .unwrap()
+            .replace(
+                r#"members = ["common", "puzzles/*/*", "runner"]"#,
+                r#"members = ["puzzles/2023/01"]"#,
+            )
+            .replace(
+                r#"common = { path = "./common" }"#,
+                &format!("common = {{ path = {:?} }}", real_root.join("common")),
+            );
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+        fs::copy(real_root.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+        scaffold(&root, 2023, 1).unwrap();
+
+        let output = std::process::Command::new(std::env::var("CARGO").unwrap_or("cargo".into()))
+            .args(["test", "--quiet", "--offline", "--manifest-path"])
+            .arg(root.join("Cargo.toml"))
+            .env("CARGO_TARGET_DIR", real_root.join("target/scaffold-test"))
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "the new day's tests failed:\n{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn should_refuse_to_clobber_existing_day() {
+        let root = fake_root("existing");
+        let err = scaffold(&root, 2024, 6).unwrap_err();
+
+        assert!(err.ends_with("already exists"));
+        assert!(scaffold(&root, 2024, 26).is_err());
+        assert!(scaffold(&root, 1999, 1).is_err());
+        assert_eq!(fs::read_dir(root.join("puzzles/2024")).unwrap().count(), 1);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,313 @@
+use colored::Colorize;
+use common::puzzle::{AnswerSink, DaySelection, PuzzleArgs, PuzzleEntry, Registry};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often to look for changed files.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Files that puzzles write into their own `data` directories, which shouldn't
+/// set off another run.
+const GENERATED_PREFIXES: &[&str] = &["soln-", "history"];
+
+/// The modification time and size of every file in some directories.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Snapshot(BTreeMap<PathBuf, (SystemTime, u64)>);
+
+impl Snapshot {
+    fn take(dirs: &[PathBuf]) -> Self {
+        let mut files = BTreeMap::new();
+        let mut pending = dirs.to_vec();
+
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in entries.filter_map(Result::ok) {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let Ok(meta) = entry.metadata() else {
+                    continue;
+                };
+
+                if meta.is_dir() {
+                    pending.push(entry.path());
+                } else if !name.starts_with('.')
+                    && !GENERATED_PREFIXES.iter().any(|p| name.starts_with(p))
+                {
+                    let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    files.insert(entry.path(), (modified, meta.len()));
+                }
+            }
+        }
+
+        Self(files)
+    }
+}
+
+/// One solved part, as printed by a puzzle binary with `--format json`.
+#[derive(Clone, Debug, Deserialize)]
+struct Record {
+    part: String,
+    example: Option<String>,
+    answer: serde_json::Value,
+    duration_ns: u64,
+    error: Option<String>,
+    check: Option<Check>,
+}
+
+/// How a solution compared to its expected answer, if it was checked.
+#[derive(Clone, Debug, Deserialize)]
+struct Check {
+    status: String,
+}
+
+impl Record {
+    fn key(&self) -> (String, Option<String>) {
+        (self.part.clone(), self.example.clone())
+    }
+
+    fn outcome(&self) -> String {
+        match (&self.error, &self.answer) {
+            (Some(err), _) => format!("failed: {err}"),
+            (None, serde_json::Value::Null) => "Unsolved!".into(),
+            (None, serde_json::Value::String(s)) => s.clone(),
+            (None, answer) => answer.to_string(),
+        }
+    }
+}
+
+/// Runs the days in `days` (as `aoc run` would, with `args`), then reruns any of them
+/// whose `src` or `data` directory changes, rebuilding them first. Each answer is
+/// compared to the one from the previous run. Only returns if there's nothing to watch.
+pub fn watch(root: &Path, registry: &Registry, days: &DaySelection, args: &PuzzleArgs) -> bool {
+    args.format.configure();
+    let entries = registry
+        .entries()
+        .filter(|entry| entry.day().is_some_and(|day| days.contains(day)))
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        eprintln!("{}", "No puzzles found for the given days.".red());
+        return false;
+    }
+
+    let watched = entries
+        .iter()
+        .map(|entry| {
+            let data_dir = registry.data_dir(entry);
+            let src_dir = data_dir.with_file_name("src");
+            vec![src_dir, data_dir]
+        })
+        .collect::<Vec<_>>();
+
+    let mut snapshots = watched
+        .iter()
+        .map(|dirs| Snapshot::take(dirs))
+        .collect::<Vec<_>>();
+    let mut previous = vec![BTreeMap::new(); entries.len()];
+    let mut changed = (0..entries.len()).collect::<Vec<_>>();
+
+    // every save would otherwise add to the history and overwrite the saved solutions
+    let args = &rerun_args(args);
+
+    loop {
+        for &idx in &changed {
+            if let Some(records) = run_day(root, entries[idx], args) {
+                print_records(entries[idx], &records, &previous[idx]);
+                previous[idx] = records
+                    .into_iter()
+                    .map(|r| (r.key(), r.outcome()))
+                    .collect();
+            }
+        }
+        println!("{}", "Watching for changes... (Ctrl-C to stop)".dimmed());
+
+        changed = loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let changed = (0..entries.len())
+                .filter(|&idx| Snapshot::take(&watched[idx]) != snapshots[idx])
+                .collect::<Vec<_>>();
+            if changed.is_empty() {
+                continue;
+            }
+
+            // wait for things to settle down, in case several files are being saved
+            thread::sleep(POLL_INTERVAL);
+            for &idx in &changed {
+                snapshots[idx] = Snapshot::take(&watched[idx]);
+            }
+            break changed;
+        };
+        println!();
+    }
+}
+
+/// The args to run each day with: the same as `args`, except that answers are
+/// only printed, unless some sinks were asked for.
+fn rerun_args(args: &PuzzleArgs) -> PuzzleArgs {
+    let mut args = args.clone();
+    if args.sinks.is_empty() {
+        args.sinks = vec![AnswerSink::Stdout];
+    }
+    args
+}
+
+/// Builds and runs a day's puzzle binary, returning what it solved, or `None`
+/// if it couldn't be built or run (cargo's errors are passed straight through).
+fn run_day(root: &Path, entry: &PuzzleEntry, args: &PuzzleArgs) -> Option<Vec<Record>> {
+    println!("{}", format!("Building Puzzle {}...", entry.name).dimmed());
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg("run")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(root.join("Cargo.toml"))
+        .args(["--bin", &format!("puzzle{}_{}", entry.year, entry.name)]);
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    cmd.arg("--")
+        .args(args.to_args())
+        .args(["--format", "json"])
+        .stderr(Stdio::inherit());
+
+    let output = match cmd.output() {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("{} could not run cargo: {err}", "Error:".red().bold());
+            return None;
+        }
+    };
+
+    let records = parse_records(&String::from_utf8_lossy(&output.stdout));
+    if records.is_empty() && !output.status.success() {
+        eprintln!("{}", format!("Puzzle {} didn't run", entry.name).red());
+        return None;
+    }
+
+    Some(records)
+}
+
+fn parse_records(stdout: &str) -> Vec<Record> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Prints each solved part, along with how its answer compares to the last run's.
+fn print_records(
+    entry: &PuzzleEntry,
+    records: &[Record],
+    previous: &BTreeMap<(String, Option<String>), String>,
+) {
+    for record in records {
+        let example = match &record.example {
+            Some(name) => format!(" ({})", format!("example {name}").yellow()),
+            None => "".into(),
+        };
+        let outcome = record.outcome();
+        let diff = match previous.get(&record.key()) {
+            None => "".normal(),
+            Some(before) if *before == outcome => " (unchanged)".dimmed(),
+            Some(before) => format!(" (was {before})").yellow().bold(),
+        };
+        let outcome = match record.error {
+            Some(_) => outcome.red(),
+            None if outcome.contains('\n') => format!("\n{outcome}").magenta(),
+            None => outcome.magenta(),
+        };
+        let mark = match record.check.as_ref().map(|check| check.status.as_str()) {
+            Some("pass") => "✔ ".green(),
+            Some("fail") => "✘ ".red(),
+            _ => "".normal(),
+        };
+        let duration = Duration::from_nanos(record.duration_ns);
+
+        println!(
+            "{}{example}: {mark}{}{diff} {}",
+            format!("Puzzle {} Part {}", entry.name, record.part).blue(),
+            outcome.bold(),
+            format!("(in {duration:?})").dimmed()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn should_pass_args_on() {
+        let args = PuzzleArgs::parse_from([
+            "aoc",
+            "--part",
+            "b",
+            "-e",
+            "larger",
+            "--check",
+            "--param",
+            "size=7",
+            "--timeout",
+            "2.5",
+            "--memory",
+        ]);
+        let passed_on = PuzzleArgs::parse_from(
+            ["puzzle"]
+                .into_iter()
+                .chain(args.to_args().iter().map(String::as_str)),
+        );
+
+        assert_eq!(format!("{passed_on:?}"), format!("{args:?}"));
+    }
+
+    #[test]
+    fn should_only_use_sinks_that_were_asked_for() {
+        let rerun = rerun_args(&PuzzleArgs::parse_from(["aoc", "--copy"]));
+        assert_eq!(
+            rerun.answer_sinks(),
+            [AnswerSink::Stdout, AnswerSink::Clipboard]
+        );
+
+        let rerun = rerun_args(&PuzzleArgs::parse_from(["aoc", "--sink", "history"]));
+        assert_eq!(rerun.answer_sinks(), [AnswerSink::History]);
+    }
+
+    #[test]
+    fn should_parse_records() {
+        let stdout = "{\"year\":2024,\"day\":\"06\",\"part\":\"A\",\"description\":\"\",\"input_kind\":\"example\",\"example\":\"1\",\"answer\":41,\"duration_ns\":12}\nnot json\n{\"year\":2024,\"day\":\"06\",\"part\":\"B\",\"description\":\"\",\"input_kind\":\"input\",\"answer\":null,\"duration_ns\":3,\"error\":\"oops\"}\n";
+        let records = parse_records(stdout);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].key(), ("A".into(), Some("1".into())));
+        assert_eq!(records[0].outcome(), "41");
+        assert_eq!(records[1].outcome(), "failed: oops");
+    }
+
+    #[test]
+    fn should_notice_changes_but_not_solutions() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "// one").unwrap();
+        let dirs = [dir.clone()];
+        let before = Snapshot::take(&dirs);
+
+        fs::write(dir.join("soln-a"), "41").unwrap();
+        assert_eq!(Snapshot::take(&dirs), before);
+
+        fs::write(dir.join("src/lib.rs"), "// three").unwrap();
+        assert_ne!(Snapshot::take(&dirs), before);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
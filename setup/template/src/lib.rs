@@ -1,2 +1,24 @@
 pub mod puzzleDAYNUMa;
 pub mod puzzleDAYNUMb;
+
+pub struct PuzzleDAYNUM {}
+
+impl common::puzzle::Puzzle for PuzzleDAYNUM {
+    type PartA = puzzleDAYNUMa::PuzzleDAYNUMa;
+    type PartB = puzzleDAYNUMb::PuzzleDAYNUMb;
+
+    fn name() -> &'static str {
+        "DAYNUM"
+    }
+
+    fn year() -> u32 {
+        YEARNUM
+    }
+}
+
+// remove these once the example answers are in data/answers.toml
+common::example_tests!(
+    PuzzleDAYNUM,
+    a: ignore = "no example answer for part A yet",
+    b: ignore = "no example answer for part B yet"
+);
@@ -1,20 +1,9 @@
-use common::puzzle::Puzzle;
-use puzzleDAYNUM::{puzzleDAYNUMa::PuzzleDAYNUMa, puzzleDAYNUMb::PuzzleDAYNUMb};
+use common::puzzle::{Puzzle, TrackingAllocator};
+use puzzleYEARNUM_DAYNUM::PuzzleDAYNUM;
 
-fn main() {
-    let input = include_str!("../data/input");
-    let example = include_str!("../data/example");
-
-    PuzzleDAYNUM::run(input, example);
-}
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
 
-struct PuzzleDAYNUM {}
-
-impl Puzzle for PuzzleDAYNUM {
-    type PartA = PuzzleDAYNUMa;
-    type PartB = PuzzleDAYNUMb;
-
-    fn name() -> &'static str {
-        "DAYNUM"
-    }
+fn main() {
+    PuzzleDAYNUM::run(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
 }
@@ -1,13 +1,15 @@
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct PuzzleDAYNUMa {}
 
 impl PuzzlePart for PuzzleDAYNUMa {
+    type Params = ();
+
     fn description() -> &'static str {
         "Puzzle DAYNUM Part A"
     }
 
-    fn solve(_input: &str) -> String {
-        "Unsolved!".into()
+    fn solve(_input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(Answer::Unsolved)
     }
 }
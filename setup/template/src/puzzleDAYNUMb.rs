@@ -1,13 +1,15 @@
-use common::puzzle::PuzzlePart;
+use common::puzzle::{Answer, PuzzleError, PuzzlePart};
 
 pub struct PuzzleDAYNUMb {}
 
 impl PuzzlePart for PuzzleDAYNUMb {
+    type Params = ();
+
     fn description() -> &'static str {
         "Puzzle DAYNUM Part B"
     }
 
-    fn solve(_input: &str) -> String {
-        "Unsolved!".into()
+    fn solve(_input: &str, _params: &()) -> Result<Answer, PuzzleError> {
+        Ok(Answer::Unsolved)
     }
 }